@@ -15,18 +15,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 1: Welcome & Main Menu
     let main_menu_buttons = vec![
-        InteractiveButton {
-            id: "new_account".to_string(),
-            title: "🆕 Open Account".to_string(),
-        },
-        InteractiveButton {
-            id: "existing_customer".to_string(),
-            title: "👤 Existing Customer".to_string(),
-        },
-        InteractiveButton {
-            id: "general_info".to_string(),
-            title: "ℹ️ General Info".to_string(),
-        },
+        InteractiveButton::reply("new_account", "🆕 Open Account"),
+        InteractiveButton::reply("existing_customer", "👤 Existing Customer"),
+        InteractiveButton::reply("general_info", "ℹ️ General Info"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -151,18 +142,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 4: Security Alert with Actions
     let security_buttons = vec![
-        InteractiveButton {
-            id: "confirm_transaction".to_string(),
-            title: "✅ Yes, it's me".to_string(),
-        },
-        InteractiveButton {
-            id: "block_transaction".to_string(),
-            title: "🚨 Block & Report".to_string(),
-        },
-        InteractiveButton {
-            id: "call_support".to_string(),
-            title: "📞 Call Support".to_string(),
-        },
+        InteractiveButton::reply("confirm_transaction", "✅ Yes, it's me"),
+        InteractiveButton::reply("block_transaction", "🚨 Block & Report"),
+        InteractiveButton::reply("call_support", "📞 Call Support"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -186,14 +168,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 5: Investment Opportunities
     let investment_buttons = vec![
-        InteractiveButton {
-            id: "learn_more".to_string(),
-            title: "📚 Learn More".to_string(),
-        },
-        InteractiveButton {
-            id: "start_sip".to_string(),
-            title: "🚀 Start SIP".to_string(),
-        },
+        InteractiveButton::reply("learn_more", "📚 Learn More"),
+        InteractiveButton::reply("start_sip", "🚀 Start SIP"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -0,0 +1,66 @@
+use actix_web::{App, HttpServer};
+use twilio_rs::media_stream::{build_media_stream_scope, media_stream_handler, MediaStreamEvent};
+
+// Point a call at this server with:
+//   <Response><Start><Stream url="wss://your-domain.com/twilio/stream"/></Start>...</Response>
+// and set MEDIA_STREAM_URL to that same wss:// URL; upgrade requests are
+// rejected unless Twilio signed them for it with TWILIO_AUTH_TOKEN.
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    dotenv::dotenv().ok();
+    let stream_url = std::env::var("MEDIA_STREAM_URL")
+        .unwrap_or_else(|_| "wss://your-domain.com/twilio/stream".to_string());
+    println!("Media Streams server at ws://127.0.0.1:8080/twilio/stream");
+
+    HttpServer::new(move || {
+        let handler = media_stream_handler(|event, mut sender| async move {
+            match event {
+                MediaStreamEvent::Start { start, .. } => {
+                    println!(
+                        "🎙️ Stream {} started for call {} ({} @ {} Hz)",
+                        start.stream_sid,
+                        start.call_sid,
+                        start.media_format.encoding,
+                        start.media_format.sample_rate
+                    );
+                }
+                MediaStreamEvent::Media { media, .. } => match media.decode_pcm() {
+                    Ok(frame) => {
+                        let energy = frame
+                            .samples
+                            .iter()
+                            .map(|s| (*s as f64).powi(2))
+                            .sum::<f64>()
+                            / frame.samples.len().max(1) as f64;
+                        println!(
+                            "🔊 {} chunk {} rms={:.0}",
+                            frame.track,
+                            frame.chunk,
+                            energy.sqrt()
+                        );
+                    }
+                    Err(e) => eprintln!("⚠️ Bad media payload: {}", e),
+                },
+                MediaStreamEvent::Dtmf { dtmf, .. } => {
+                    println!("🔢 Caller pressed {}", dtmf.digit);
+                    // Barge-in: drop queued audio and acknowledge with a mark.
+                    let _ = sender.clear().await;
+                    let _ = sender.mark("dtmf").await;
+                }
+                MediaStreamEvent::Mark { mark, .. } => println!("✅ Mark {} played", mark.name),
+                MediaStreamEvent::Stop { stop, .. } => {
+                    println!("🛑 Call {} stream stopped", stop.call_sid)
+                }
+                MediaStreamEvent::Unrecognised { error, .. } => {
+                    eprintln!("⚠️ Unrecognised media stream message: {}", error)
+                }
+                MediaStreamEvent::Connected { .. } => {}
+            }
+        });
+
+        App::new().service(build_media_stream_scope(&stream_url, handler))
+    })
+    .bind("127.0.0.1:8080")?
+    .run()
+    .await
+}
@@ -1,10 +1,6 @@
-
 use twilio_rs::{
     client::TwilioClient,
-    whatsapp::{
-        send_whatsapp_interactive_buttons, send_whatsapp_text,
-        InteractiveButton,
-    },
+    whatsapp::{send_whatsapp_interactive_buttons, send_whatsapp_text, InteractiveButton},
 };
 
 #[tokio::main]
@@ -16,18 +12,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 1: Language Selection
     let language_buttons = vec![
-        InteractiveButton {
-            id: "english".to_string(),
-            title: "🇺🇸 English".to_string(),
-        },
-        InteractiveButton {
-            id: "hindi".to_string(),
-            title: "🇮🇳 हिंदी".to_string(),
-        },
-        InteractiveButton {
-            id: "spanish".to_string(),
-            title: "🇪🇸 Español".to_string(),
-        },
+        InteractiveButton::reply("english", "🇺🇸 English"),
+        InteractiveButton::reply("hindi", "🇮🇳 हिंदी"),
+        InteractiveButton::reply("spanish", "🇪🇸 Español"),
     ];
 
     match send_whatsapp_interactive_buttons(
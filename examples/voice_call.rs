@@ -0,0 +1,36 @@
+use twilio_rs::{
+    client::TwilioClient,
+    twiml::{Pause, Say, VoiceResponse},
+    voice::{CallInstructions, CallStatusEvent, CreateCallRequest, MachineDetection},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = TwilioClient::new();
+
+    let twiml = VoiceResponse::new()
+        .say(Say::new("Hello from Rust!").with_voice("Polly.Aditi"))
+        .pause(Pause::new(1))
+        .say(Say::new("Goodbye!"))
+        .hangup()
+        .to_xml()?;
+
+    let mut request = CreateCallRequest::new("+917569785621", CallInstructions::twiml(&twiml));
+    request.status_callback = Some("https://your-domain.com/twilio/voice/status".to_string());
+    request.status_callback_events = vec![CallStatusEvent::Answered, CallStatusEvent::Completed];
+    request.machine_detection = Some(MachineDetection::Enable);
+    request.timeout = Some(30);
+
+    let call = client.create_call(&request).await?;
+    println!("📞 Call created! SID: {} ({})", call.sid, call.status);
+
+    tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
+
+    let call = client.fetch_call(&call.sid).await?;
+    if !call.status.is_final() {
+        client.hangup_call(&call.sid).await?;
+        println!("☎️ Call hung up");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use actix_web::{App, HttpServer};
+use twilio_rs::{
+    build_voice_webhook_scope,
+    twiml::{Dial, Gather, GatherInput, Say, VoiceResponse},
+    voice_webhook::VoiceWebhookHandlers,
+};
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let base_url = "https://your-domain.com/twilio/voice";
+
+    println!("Starting IVR at http://127.0.0.1:8080/twilio/voice");
+
+    HttpServer::new(move || {
+        let handlers = VoiceWebhookHandlers::new()
+            .on_incoming_call(|call| async move {
+                println!("📞 Incoming call from {}", call.from);
+                let mut menu = Gather::new("/twilio/voice/gather")
+                    .say(Say::new("Press 1 or say sales. Press 2 or say support."));
+                menu.input = vec![GatherInput::Dtmf, GatherInput::Speech];
+                menu.num_digits = Some(1);
+                menu.hints = vec!["sales".to_string(), "support".to_string()];
+
+                VoiceResponse::new()
+                    .gather(menu)
+                    .say(Say::new("We didn't receive any input. Goodbye!"))
+            })
+            .on_gather(|result| async move {
+                let choice = result
+                    .digits
+                    .or(result.speech_result.map(|s| s.to_lowercase()));
+                match choice.as_deref() {
+                    Some("1") | Some("sales") => VoiceResponse::new()
+                        .say(Say::new("Connecting you to sales."))
+                        .dial(Dial::number("+15005550006")),
+                    Some("2") | Some("support") => VoiceResponse::new()
+                        .say(Say::new("Connecting you to support."))
+                        .dial(Dial::number("+15005550007")),
+                    _ => VoiceResponse::new()
+                        .say(Say::new("Sorry, I didn't get that."))
+                        .hangup(),
+                }
+            })
+            .on_call_status(|status| async move {
+                println!(
+                    "☎️ Call {} is {} ({:?}s)",
+                    status.call.call_sid, status.call.call_status, status.call_duration
+                );
+                VoiceResponse::new()
+            });
+
+        App::new().service(build_voice_webhook_scope(base_url, handlers))
+    })
+    .bind("127.0.0.1:8080")?
+    .run()
+    .await
+}
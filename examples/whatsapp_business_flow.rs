@@ -1,9 +1,8 @@
 use twilio_rs::{
     client::TwilioClient,
     whatsapp::{
-        send_whatsapp_appointment_reminder, send_whatsapp_interactive_buttons,
-        send_whatsapp_order_status, send_whatsapp_text, send_whatsapp_upi_payment,
-        InteractiveButton,
+        send_whatsapp_appointment_reminder, send_whatsapp_interactive_buttons, send_whatsapp_text,
+        send_whatsapp_upi_payment, InteractiveButton,
     },
 };
 
@@ -30,18 +29,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 2: Service Selection
     let service_buttons = vec![
-        InteractiveButton {
-            id: "web_project".to_string(),
-            title: "🌐 Web Development".to_string(),
-        },
-        InteractiveButton {
-            id: "mobile_project".to_string(),
-            title: "📱 Mobile App".to_string(),
-        },
-        InteractiveButton {
-            id: "consultation".to_string(),
-            title: "💬 Free Consultation".to_string(),
-        },
+        InteractiveButton::reply("web_project", "🌐 Web Development"),
+        InteractiveButton::reply("mobile_project", "📱 Mobile App"),
+        InteractiveButton::reply("consultation", "💬 Free Consultation"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -110,18 +100,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 6: Project Progress Update
     let progress_buttons = vec![
-        InteractiveButton {
-            id: "view_demo".to_string(),
-            title: "👀 View Demo".to_string(),
-        },
-        InteractiveButton {
-            id: "request_changes".to_string(),
-            title: "✏️ Request Changes".to_string(),
-        },
-        InteractiveButton {
-            id: "approve_design".to_string(),
-            title: "✅ Approve Design".to_string(),
-        },
+        InteractiveButton::reply("view_demo", "👀 View Demo"),
+        InteractiveButton::reply("request_changes", "✏️ Request Changes"),
+        InteractiveButton::reply("approve_design", "✅ Approve Design"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -173,18 +154,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 9: Follow-up & Future Services
     let followup_buttons = vec![
-        InteractiveButton {
-            id: "mobile_app".to_string(),
-            title: "📱 Build Mobile App".to_string(),
-        },
-        InteractiveButton {
-            id: "marketing".to_string(),
-            title: "📈 Digital Marketing".to_string(),
-        },
-        InteractiveButton {
-            id: "maintenance".to_string(),
-            title: "🔧 Maintenance Plan".to_string(),
-        },
+        InteractiveButton::reply("mobile_app", "📱 Build Mobile App"),
+        InteractiveButton::reply("marketing", "📈 Digital Marketing"),
+        InteractiveButton::reply("maintenance", "🔧 Maintenance Plan"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -205,14 +177,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Step 10: Customer Success & Testimonial Request
     let testimonial_buttons = vec![
-        InteractiveButton {
-            id: "write_review".to_string(),
-            title: "⭐ Write Review".to_string(),
-        },
-        InteractiveButton {
-            id: "refer_friend".to_string(),
-            title: "👥 Refer a Friend".to_string(),
-        },
+        InteractiveButton::reply("write_review", "⭐ Write Review"),
+        InteractiveButton::reply("refer_friend", "👥 Refer a Friend"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -1,8 +1,8 @@
 use twilio_rs::{
     client::TwilioClient,
     whatsapp::{
-        send_whatsapp_interactive_buttons, send_whatsapp_order_status, send_whatsapp_quick_replies,
-        InteractiveButton,
+        send_whatsapp_interactive_buttons, send_whatsapp_order_status, send_whatsapp_product_list,
+        InteractiveButton, InteractiveHeader, ProductSection,
     },
 };
 
@@ -51,26 +51,36 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Wait a bit before sending next message
     tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
 
-    println!("🛍️ Sending product recommendation...");
+    println!("🛍️ Sending product recommendations...");
 
-    // Example 3: Product Recommendation with Quick Replies
-    let product_choices = vec![
-        "📱 View Similar Products",
-        "⭐ See Reviews",
-        "💰 Check Offers",
-        "🚚 Delivery Options",
-        "❌ Not Interested",
+    // Example 3: Product Recommendations from the WhatsApp catalog
+    let catalog_id = "1234567890"; // Replace with your Meta catalog ID
+    let sections = vec![
+        ProductSection {
+            title: "Chargers".to_string(),
+            product_ids: vec!["magsafe-charger-15w".to_string(), "usb-c-20w".to_string()],
+        },
+        ProductSection {
+            title: "Accessories".to_string(),
+            product_ids: vec![
+                "screen-guard-14pro".to_string(),
+                "camera-lens-kit".to_string(),
+            ],
+        },
     ];
 
-    match send_whatsapp_quick_replies(
+    match send_whatsapp_product_list(
         &client,
         phone,
-        "🎉 Great choice! Since you loved the iPhone case, you might also like:\n\n📱 iPhone 14 Pro MagSafe Wireless Charger - ₹2,999\n✨ Features:\n• Fast 15W charging\n• MagSafe compatible\n• Premium aluminum build\n• 1-year warranty\n\n🔥 Special offer: 20% off if ordered with your recent purchase!",
-        product_choices,
+        catalog_id,
+        "🎉 Picked for you",
+        "Since you loved the iPhone case, you might also like these.\n\n🔥 Special offer: 20% off if ordered with your recent purchase!",
+        Some("Add items to your cart and send the order"),
+        sections,
     )
     .await
     {
-        Ok(response) => println!("✅ Product recommendation sent! SID: {}", response.sid),
+        Ok(response) => println!("✅ Product recommendations sent! SID: {}", response.sid),
         Err(e) => eprintln!("❌ Error: {}", e),
     }
 
@@ -81,18 +91,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 4: Post-Delivery Feedback
     let feedback_buttons = vec![
-        InteractiveButton {
-            id: "rate_5".to_string(),
-            title: "⭐⭐⭐⭐⭐ Excellent".to_string(),
-        },
-        InteractiveButton {
-            id: "rate_4".to_string(),
-            title: "⭐⭐⭐⭐ Good".to_string(),
-        },
-        InteractiveButton {
-            id: "rate_low".to_string(),
-            title: "⭐⭐⭐ Needs Improvement".to_string(),
-        },
+        InteractiveButton::reply("rate_5", "⭐⭐⭐⭐⭐ Excellent"),
+        InteractiveButton::reply("rate_4", "⭐⭐⭐⭐ Good"),
+        InteractiveButton::reply("rate_low", "⭐⭐⭐ Could be better"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -116,14 +117,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 5: Loyalty Program Notification
     let loyalty_buttons = vec![
-        InteractiveButton {
-            id: "view_rewards".to_string(),
-            title: "🎁 View My Rewards".to_string(),
-        },
-        InteractiveButton {
-            id: "redeem_points".to_string(),
-            title: "✨ Redeem Points".to_string(),
-        },
+        InteractiveButton::reply("view_rewards", "🎁 View My Rewards"),
+        InteractiveButton::reply("redeem_points", "✨ Redeem Points"),
     ];
 
     match send_whatsapp_interactive_buttons(
@@ -140,6 +135,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(e) => eprintln!("❌ Error: {}", e),
     }
 
+    // Wait a bit before sending next message
+    tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+
+    println!("🏷️ Sending coupon offer...");
+
+    // Example 6: Coupon with an image header and call-to-action buttons
+    let coupon_buttons = vec![
+        InteractiveButton::url("🛒 Shop Now", "https://example.com/sale"),
+        InteractiveButton::copy_code("📋 Copy Code", "VIP20"),
+    ];
+
+    match send_whatsapp_interactive_buttons(
+        &client,
+        phone,
+        InteractiveHeader::Image("https://example.com/images/vip-sale.jpg".to_string()),
+        "As a VIP member you get 20% off everything this weekend! 🎉\n\nUse code VIP20 at checkout.",
+        Some("Valid until Sunday midnight"),
+        coupon_buttons,
+    )
+    .await
+    {
+        Ok(response) => println!("✅ Coupon offer sent! SID: {}", response.sid),
+        Err(e) => eprintln!("❌ Error: {}", e),
+    }
+
     println!("🛍️ All e-commerce messages sent successfully!");
     Ok(())
 }
@@ -13,18 +13,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("🔄 Sending interactive button message...");
     let service_buttons = vec![
-        InteractiveButton {
-            id: "billing".to_string(),
-            title: "💳 Billing Issue".to_string(),
-        },
-        InteractiveButton {
-            id: "technical".to_string(),
-            title: "🔧 Technical Support".to_string(),
-        },
-        InteractiveButton {
-            id: "general".to_string(),
-            title: "💬 General Inquiry".to_string(),
-        },
+        InteractiveButton::reply("billing", "💳 Billing Issue"),
+        InteractiveButton::reply("technical", "🔧 Technical Support"),
+        InteractiveButton::reply("general", "💬 General Inquiry"),
     ];
 
     match send_whatsapp_interactive_buttons(
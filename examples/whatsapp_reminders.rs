@@ -15,18 +15,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 1: Task Reminder with Action Buttons
     let task_buttons = vec![
-        InteractiveButton {
-            id: "completed".to_string(),
-            title: "✅ Mark Complete".to_string(),
-        },
-        InteractiveButton {
-            id: "snooze".to_string(),
-            title: "⏰ Snooze 1hr".to_string(),
-        },
-        InteractiveButton {
-            id: "reschedule".to_string(),
-            title: "📅 Reschedule".to_string(),
-        },
+        InteractiveButton::reply("completed", "✅ Mark Complete"),
+        InteractiveButton::reply("snooze", "⏰ Snooze 1hr"),
+        InteractiveButton::reply("reschedule", "📅 Reschedule"),
     ];
 
     let task_reminder = ReminderMessage {
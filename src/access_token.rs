@@ -0,0 +1,500 @@
+// access_token.rs
+
+use crate::client::TwilioClient;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default lifetime of a token, in seconds.
+pub const DEFAULT_TTL: u64 = 3600;
+/// Longest lifetime Twilio accepts, in seconds.
+pub const MAX_TTL: u64 = 86400;
+
+const CONTENT_TYPE: &str = "twilio-fpa;v=1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessTokenError {
+    MissingApiKey,
+    InvalidTtl(u64),
+    Malformed(String),
+    InvalidSignature,
+    Expired { expired_at: u64 },
+}
+
+impl fmt::Display for AccessTokenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccessTokenError::MissingApiKey => {
+                write!(f, "Access tokens must be signed with an API key secret")
+            }
+            AccessTokenError::InvalidTtl(ttl) => {
+                write!(f, "Token TTL of {}s exceeds the {}s maximum", ttl, MAX_TTL)
+            }
+            AccessTokenError::Malformed(reason) => write!(f, "Malformed access token: {}", reason),
+            AccessTokenError::InvalidSignature => write!(f, "Access token signature is invalid"),
+            AccessTokenError::Expired { expired_at } => {
+                write!(f, "Access token expired at {}", expired_at)
+            }
+        }
+    }
+}
+
+impl Error for AccessTokenError {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VoiceIncoming {
+    pub allow: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VoiceOutgoing {
+    /// TwiML App whose voice URL handles outgoing calls.
+    pub application_sid: String,
+    /// Passed to the TwiML App's voice URL with every outgoing call.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct VoiceGrant {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incoming: Option<VoiceIncoming>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outgoing: Option<VoiceOutgoing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push_credential_sid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+}
+
+impl VoiceGrant {
+    /// Allows outgoing calls through the given TwiML App.
+    pub fn outgoing(application_sid: &str) -> Self {
+        Self {
+            outgoing: Some(VoiceOutgoing {
+                application_sid: application_sid.to_string(),
+                params: HashMap::new(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Allows the identity to receive incoming calls.
+    pub fn allow_incoming(mut self) -> Self {
+        self.incoming = Some(VoiceIncoming { allow: true });
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct VideoGrant {
+    /// Restricts the token to one room (name or SID); any room if unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub room: Option<String>,
+}
+
+/// Grant for the Conversations (and legacy Chat) SDKs.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChatGrant {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_sid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push_credential_sid: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncGrant {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_sid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
+}
+
+/// Twilio Live playback grant, as returned by the PlaybackGrant API.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct PlaybackGrant {
+    pub grant: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Grants {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<VoiceGrant>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<VideoGrant>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat: Option<ChatGrant>,
+    #[serde(rename = "data_sync", skip_serializing_if = "Option::is_none")]
+    pub sync: Option<SyncGrant>,
+    #[serde(rename = "player", skip_serializing_if = "Option::is_none")]
+    pub playback: Option<PlaybackGrant>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AccessTokenHeader {
+    pub alg: String,
+    pub typ: String,
+    pub cty: String,
+    /// Twilio region the token is valid in, e.g. `au1` or `ie1`.
+    #[serde(rename = "twr", skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AccessTokenClaims {
+    pub jti: String,
+    /// API key SID that signed the token.
+    pub iss: String,
+    /// Account SID.
+    pub sub: String,
+    pub iat: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<u64>,
+    pub exp: u64,
+    pub grants: Grants,
+}
+
+/// A verified, decoded access token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedAccessToken {
+    pub header: AccessTokenHeader,
+    pub claims: AccessTokenClaims,
+}
+
+/// Builds short-lived HS256 tokens for the Twilio client SDKs.
+///
+/// # Example
+/// ```
+/// use twilio_rs::access_token::{AccessToken, VoiceGrant};
+///
+/// let jwt = AccessToken::new("ACxxx", "SKxxx", "secret", "alice")
+///     .voice_grant(VoiceGrant::outgoing("APxxx").allow_incoming())
+///     .ttl(600)
+///     .to_jwt()
+///     .unwrap();
+///
+/// let decoded = AccessToken::decode(&jwt, "secret").unwrap();
+/// assert_eq!(decoded.claims.grants.identity.as_deref(), Some("alice"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AccessToken {
+    pub account_sid: String,
+    pub api_key_sid: String,
+    api_key_secret: String,
+    pub grants: Grants,
+    /// Lifetime in seconds; defaults to `DEFAULT_TTL`.
+    pub ttl: u64,
+    /// Seconds since the epoch before which the token is not valid.
+    pub not_before: Option<u64>,
+    pub region: Option<String>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+fn sign(message: &str, secret: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("Invalid HMAC key");
+    mac.update(message.as_bytes());
+    mac
+}
+
+fn encode_part<T: Serialize>(part: &T) -> String {
+    URL_SAFE_NO_PAD.encode(serde_json::to_vec(part).expect("token parts always serialize"))
+}
+
+fn decode_part<T: for<'de> Deserialize<'de>>(part: &str) -> Result<T, AccessTokenError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(part)
+        .map_err(|e| AccessTokenError::Malformed(e.to_string()))?;
+    serde_json::from_slice(&bytes).map_err(|e| AccessTokenError::Malformed(e.to_string()))
+}
+
+impl AccessToken {
+    pub fn new(account_sid: &str, api_key_sid: &str, api_key_secret: &str, identity: &str) -> Self {
+        Self {
+            account_sid: account_sid.to_string(),
+            api_key_sid: api_key_sid.to_string(),
+            api_key_secret: api_key_secret.to_string(),
+            grants: Grants {
+                identity: Some(identity.to_string()),
+                ..Default::default()
+            },
+            ttl: DEFAULT_TTL,
+            not_before: None,
+            region: None,
+        }
+    }
+
+    pub fn ttl(mut self, seconds: u64) -> Self {
+        self.ttl = seconds;
+        self
+    }
+
+    pub fn region(mut self, region: &str) -> Self {
+        self.region = Some(region.to_string());
+        self
+    }
+
+    pub fn voice_grant(mut self, grant: VoiceGrant) -> Self {
+        self.grants.voice = Some(grant);
+        self
+    }
+
+    pub fn video_grant(mut self, grant: VideoGrant) -> Self {
+        self.grants.video = Some(grant);
+        self
+    }
+
+    pub fn chat_grant(mut self, grant: ChatGrant) -> Self {
+        self.grants.chat = Some(grant);
+        self
+    }
+
+    pub fn sync_grant(mut self, grant: SyncGrant) -> Self {
+        self.grants.sync = Some(grant);
+        self
+    }
+
+    pub fn playback_grant(mut self, grant: PlaybackGrant) -> Self {
+        self.grants.playback = Some(grant);
+        self
+    }
+
+    /// Signs the token, valid from now for `ttl` seconds.
+    pub fn to_jwt(&self) -> Result<String, AccessTokenError> {
+        self.to_jwt_at(now())
+    }
+
+    /// Signs the token as if issued at `issued_at` (seconds since the epoch).
+    pub fn to_jwt_at(&self, issued_at: u64) -> Result<String, AccessTokenError> {
+        if self.ttl > MAX_TTL {
+            return Err(AccessTokenError::InvalidTtl(self.ttl));
+        }
+
+        let header = AccessTokenHeader {
+            alg: "HS256".to_string(),
+            typ: "JWT".to_string(),
+            cty: CONTENT_TYPE.to_string(),
+            region: self.region.clone(),
+        };
+        let claims = AccessTokenClaims {
+            jti: format!("{}-{}", self.api_key_sid, issued_at),
+            iss: self.api_key_sid.clone(),
+            sub: self.account_sid.clone(),
+            iat: issued_at,
+            nbf: self.not_before,
+            exp: issued_at + self.ttl,
+            grants: self.grants.clone(),
+        };
+
+        let message = format!("{}.{}", encode_part(&header), encode_part(&claims));
+        let signature = sign(&message, &self.api_key_secret).finalize().into_bytes();
+        Ok(format!("{}.{}", message, URL_SAFE_NO_PAD.encode(signature)))
+    }
+
+    /// Verifies a token against `api_key_secret` and decodes it, rejecting
+    /// expired tokens.
+    pub fn decode(jwt: &str, api_key_secret: &str) -> Result<DecodedAccessToken, AccessTokenError> {
+        Self::decode_at(jwt, api_key_secret, now())
+    }
+
+    /// Like `decode`, checking expiry against `now` (seconds since the epoch).
+    pub fn decode_at(
+        jwt: &str,
+        api_key_secret: &str,
+        now: u64,
+    ) -> Result<DecodedAccessToken, AccessTokenError> {
+        let mut parts = jwt.split('.');
+        let (header, claims, signature) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(header), Some(claims), Some(signature)) if parts.next().is_none() => {
+                (header, claims, signature)
+            }
+            _ => {
+                return Err(AccessTokenError::Malformed(
+                    "expected three dot-separated parts".to_string(),
+                ))
+            }
+        };
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|e| AccessTokenError::Malformed(e.to_string()))?;
+        sign(&format!("{}.{}", header, claims), api_key_secret)
+            .verify_slice(&signature)
+            .map_err(|_| AccessTokenError::InvalidSignature)?;
+
+        let decoded = DecodedAccessToken {
+            header: decode_part(header)?,
+            claims: decode_part(claims)?,
+        };
+        if decoded.claims.exp <= now {
+            return Err(AccessTokenError::Expired {
+                expired_at: decoded.claims.exp,
+            });
+        }
+        Ok(decoded)
+    }
+}
+
+impl TwilioClient {
+    /// Starts an access token for `identity`, signed with the client's API
+    /// key; tokens cannot be signed with the account auth token.
+    pub fn access_token(&self, identity: &str) -> Result<AccessToken, AccessTokenError> {
+        let key = self
+            .api_key
+            .as_ref()
+            .ok_or(AccessTokenError::MissingApiKey)?;
+        Ok(AccessToken::new(
+            &self.account_sid,
+            &key.sid,
+            &key.secret,
+            identity,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const ISSUED_AT: u64 = 1_700_000_000;
+
+    fn token() -> AccessToken {
+        AccessToken::new("AC123", "SK123", "secret", "alice")
+    }
+
+    #[test]
+    fn every_grant_round_trips() {
+        let mut voice = VoiceGrant::outgoing("AP123").allow_incoming();
+        if let Some(outgoing) = voice.outgoing.as_mut() {
+            outgoing
+                .params
+                .insert("plan".to_string(), "gold".to_string());
+        }
+        voice.push_credential_sid = Some("CR123".to_string());
+        let grants = Grants {
+            identity: Some("alice".to_string()),
+            voice: Some(voice.clone()),
+            video: Some(VideoGrant {
+                room: Some("standup".to_string()),
+            }),
+            chat: Some(ChatGrant {
+                service_sid: Some("IS123".to_string()),
+                ..Default::default()
+            }),
+            sync: Some(SyncGrant {
+                service_sid: Some("IS456".to_string()),
+                endpoint_id: Some("alice-browser".to_string()),
+            }),
+            playback: Some(PlaybackGrant {
+                grant: json!({ "requestCredentials": null, "playbackUrl": "https://x" }),
+            }),
+        };
+        let jwt = token()
+            .voice_grant(voice)
+            .video_grant(grants.video.clone().unwrap())
+            .chat_grant(grants.chat.clone().unwrap())
+            .sync_grant(grants.sync.clone().unwrap())
+            .playback_grant(grants.playback.clone().unwrap())
+            .ttl(600)
+            .to_jwt_at(ISSUED_AT)
+            .unwrap();
+
+        let decoded = AccessToken::decode_at(&jwt, "secret", ISSUED_AT).unwrap();
+        assert_eq!(decoded.claims.grants, grants);
+        assert_eq!(decoded.claims.iss, "SK123");
+        assert_eq!(decoded.claims.sub, "AC123");
+        assert_eq!(decoded.claims.iat, ISSUED_AT);
+        assert_eq!(decoded.claims.exp, ISSUED_AT + 600);
+        assert_eq!(decoded.header.cty, CONTENT_TYPE);
+
+        let claims: serde_json::Value = decode_part(jwt.split('.').nth(1).unwrap()).unwrap();
+        assert!(claims["grants"]["data_sync"].is_object());
+        assert!(claims["grants"]["player"].is_object());
+    }
+
+    #[test]
+    fn tampered_tokens_are_rejected() {
+        let jwt = token().to_jwt_at(ISSUED_AT).unwrap();
+        assert_eq!(
+            AccessToken::decode_at(&jwt, "other secret", ISSUED_AT),
+            Err(AccessTokenError::InvalidSignature)
+        );
+
+        let (message, _) = jwt.rsplit_once('.').unwrap();
+        let forged = format!("{}.{}", message, URL_SAFE_NO_PAD.encode([0u8; 32]));
+        assert_eq!(
+            AccessToken::decode_at(&forged, "secret", ISSUED_AT),
+            Err(AccessTokenError::InvalidSignature)
+        );
+
+        let mut parts: Vec<&str> = jwt.split('.').collect();
+        let claims = encode_part(&json!({ "grants": { "identity": "mallory" } }));
+        parts[1] = &claims;
+        assert_eq!(
+            AccessToken::decode_at(&parts.join("."), "secret", ISSUED_AT),
+            Err(AccessTokenError::InvalidSignature)
+        );
+
+        assert!(matches!(
+            AccessToken::decode_at(message, "secret", ISSUED_AT),
+            Err(AccessTokenError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn tokens_expire_after_their_ttl() {
+        let jwt = token().ttl(60).to_jwt_at(ISSUED_AT).unwrap();
+        assert!(AccessToken::decode_at(&jwt, "secret", ISSUED_AT + 59).is_ok());
+        assert_eq!(
+            AccessToken::decode_at(&jwt, "secret", ISSUED_AT + 60),
+            Err(AccessTokenError::Expired {
+                expired_at: ISSUED_AT + 60
+            })
+        );
+    }
+
+    #[test]
+    fn ttl_is_capped() {
+        assert!(token().ttl(MAX_TTL).to_jwt_at(ISSUED_AT).is_ok());
+        assert_eq!(
+            token().ttl(MAX_TTL + 1).to_jwt_at(ISSUED_AT),
+            Err(AccessTokenError::InvalidTtl(MAX_TTL + 1))
+        );
+    }
+
+    #[test]
+    fn not_before_and_region_are_encoded() {
+        let decoded =
+            AccessToken::decode_at(&token().to_jwt_at(ISSUED_AT).unwrap(), "secret", ISSUED_AT)
+                .unwrap();
+        assert_eq!(decoded.claims.nbf, None);
+        assert_eq!(decoded.header.region, None);
+
+        let mut token = token().region("ie1");
+        token.not_before = Some(ISSUED_AT + 30);
+        let jwt = token.to_jwt_at(ISSUED_AT).unwrap();
+        let decoded = AccessToken::decode_at(&jwt, "secret", ISSUED_AT).unwrap();
+        assert_eq!(decoded.claims.nbf, Some(ISSUED_AT + 30));
+        assert_eq!(decoded.header.region.as_deref(), Some("ie1"));
+
+        let header: serde_json::Value = decode_part(jwt.split('.').next().unwrap()).unwrap();
+        assert_eq!(header["twr"], "ie1");
+    }
+}
@@ -0,0 +1,99 @@
+use crate::client::{send_empty, send_json, TwilioClient};
+use reqwest::Method;
+use serde::Deserialize;
+use std::error::Error;
+
+const ACCOUNTS_API: &str = "https://accounts.twilio.com/v1";
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiKey {
+    pub sid: String,
+    pub friendly_name: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+}
+
+/// Returned only when a key is created; Twilio never shows the secret again.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NewApiKey {
+    pub sid: String,
+    pub friendly_name: Option<String>,
+    pub secret: String,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ApiKeyPage {
+    keys: Vec<ApiKey>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SecondaryAuthToken {
+    pub account_sid: String,
+    pub secondary_auth_token: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PromotedAuthToken {
+    pub account_sid: String,
+    pub auth_token: String,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub url: Option<String>,
+}
+
+impl TwilioClient {
+    pub async fn create_api_key(&self, friendly_name: &str) -> Result<NewApiKey, Box<dyn Error>> {
+        let params = [("FriendlyName", friendly_name)];
+        send_json(
+            self.request(Method::POST, &self.api_url("Keys.json"))
+                .form(&params),
+        )
+        .await
+    }
+
+    pub async fn list_api_keys(&self) -> Result<Vec<ApiKey>, Box<dyn Error>> {
+        let page: ApiKeyPage =
+            send_json(self.request(Method::GET, &self.api_url("Keys.json"))).await?;
+        Ok(page.keys)
+    }
+
+    pub async fn fetch_api_key(&self, key_sid: &str) -> Result<ApiKey, Box<dyn Error>> {
+        let url = self.api_url(&format!("Keys/{}.json", key_sid));
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    pub async fn delete_api_key(&self, key_sid: &str) -> Result<(), Box<dyn Error>> {
+        let url = self.api_url(&format!("Keys/{}.json", key_sid));
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+
+    /// Creates a secondary auth token, the first step of rotating the
+    /// account's auth token.
+    pub async fn create_secondary_auth_token(&self) -> Result<SecondaryAuthToken, Box<dyn Error>> {
+        let url = format!("{}/AuthTokens/Secondary", ACCOUNTS_API);
+        send_json(self.request(Method::POST, &url)).await
+    }
+
+    pub async fn delete_secondary_auth_token(&self) -> Result<(), Box<dyn Error>> {
+        let url = format!("{}/AuthTokens/Secondary", ACCOUNTS_API);
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+
+    /// Promotes the secondary auth token to primary and switches this client
+    /// over to it. The previous primary token stops working immediately, so
+    /// webhook validation should accept both tokens until every server has
+    /// picked up the new one (see `validate_twilio_signature_any`).
+    pub async fn promote_secondary_auth_token(
+        &mut self,
+    ) -> Result<PromotedAuthToken, Box<dyn Error>> {
+        let url = format!("{}/AuthTokens/Promote", ACCOUNTS_API);
+        let promoted: PromotedAuthToken = send_json(self.request(Method::POST, &url)).await?;
+        self.auth_token = promoted.auth_token.clone();
+        Ok(promoted)
+    }
+}
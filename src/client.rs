@@ -1,21 +1,108 @@
+use crate::content::ContentCache;
+use crate::phone::{PhoneNumber, PhoneNumberError};
 use dotenv::dotenv;
+use reqwest::{Client, Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
 use std::env;
+use std::error::Error;
+use std::fmt;
+
+/// API Key credentials (an `SK…` SID plus its secret) used in place of the
+/// account auth token for basic auth.
+#[derive(Clone)]
+pub struct ApiKeyCredentials {
+    pub sid: String,
+    pub secret: String,
+}
+
+/// Keeps the secret out of logs and panic messages.
+impl fmt::Debug for ApiKeyCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ApiKeyCredentials")
+            .field("sid", &self.sid)
+            .field("secret", &"[redacted]")
+            .finish()
+    }
+}
 
 #[derive(Clone)]
 pub struct TwilioClient {
     pub account_sid: String,
     pub auth_token: String,
     pub from_phone: String,
+    pub api_key: Option<ApiKeyCredentials>,
+    /// ISO region (e.g. `IN`) used to interpret numbers written without a
+    /// country code.
+    pub default_region: Option<String>,
+    /// Messaging Service used as the sender when a message names neither a
+    /// service nor a `from` address.
+    pub messaging_service_sid: Option<String>,
+    /// Content templates looked up by name, shared between clones.
+    pub content_cache: ContentCache,
+}
+
+impl Default for TwilioClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TwilioClient {
+    /// Builds a client from the environment.
+    ///
+    /// When `TWILIO_API_KEY_SID` and `TWILIO_API_KEY_SECRET` are set, requests
+    /// authenticate with the API key and `TWILIO_AUTH_TOKEN` becomes optional.
+    /// Webhooks still need it: without an auth token every incoming request
+    /// fails signature validation.
     pub fn new() -> Self {
         dotenv().ok();
 
+        let api_key = match (
+            env::var("TWILIO_API_KEY_SID"),
+            env::var("TWILIO_API_KEY_SECRET"),
+        ) {
+            (Ok(sid), Ok(secret)) => Some(ApiKeyCredentials { sid, secret }),
+            _ => None,
+        };
+
+        let auth_token = if api_key.is_some() {
+            env::var("TWILIO_AUTH_TOKEN").unwrap_or_default()
+        } else {
+            env::var("TWILIO_AUTH_TOKEN").expect("Missing TWILIO_AUTH_TOKEN")
+        };
+
         Self {
             account_sid: env::var("TWILIO_ACCOUNT_SID").expect("Missing TWILIO_ACCOUNT_SID"),
-            auth_token: env::var("TWILIO_AUTH_TOKEN").expect("Missing TWILIO_AUTH_TOKEN"),
+            auth_token,
             from_phone: env::var("TWILIO_PHONE_NUMBER").expect("Missing TWILIO_PHONE_NUMBER"),
+            api_key,
+            default_region: env::var("TWILIO_DEFAULT_REGION").ok(),
+            messaging_service_sid: env::var("TWILIO_MESSAGING_SERVICE_SID")
+                .ok()
+                .filter(|sid| !sid.is_empty()),
+            content_cache: ContentCache::default(),
+        }
+    }
+
+    /// Builds a client that authenticates with an API key while still
+    /// addressing resources under `account_sid`.
+    pub fn with_api_key(
+        account_sid: &str,
+        api_key_sid: &str,
+        api_key_secret: &str,
+        from_phone: &str,
+    ) -> Self {
+        Self {
+            account_sid: account_sid.to_string(),
+            auth_token: String::new(),
+            from_phone: from_phone.to_string(),
+            api_key: Some(ApiKeyCredentials {
+                sid: api_key_sid.to_string(),
+                secret: api_key_secret.to_string(),
+            }),
+            default_region: None,
+            messaging_service_sid: None,
+            content_cache: ContentCache::default(),
         }
     }
 
@@ -25,4 +112,103 @@ impl TwilioClient {
             self.account_sid
         )
     }
+
+    /// Parses `input` into E.164, using `default_region` for national-format
+    /// numbers.
+    pub fn parse_number(&self, input: &str) -> Result<PhoneNumber, PhoneNumberError> {
+        PhoneNumber::parse(input, self.default_region.as_deref())
+    }
+
+    /// The configured sender, normalized. Alphanumeric sender IDs and short
+    /// codes are passed through (see `PhoneNumber::parse_sender`).
+    pub fn from_number(&self) -> Result<PhoneNumber, PhoneNumberError> {
+        PhoneNumber::parse_sender(&self.from_phone, self.default_region.as_deref())
+    }
+
+    /// URL of a resource under the account, e.g. `api_url("Keys.json")`.
+    pub fn api_url(&self, path: &str) -> String {
+        format!(
+            "https://api.twilio.com/2010-04-01/Accounts/{}/{}",
+            self.account_sid, path
+        )
+    }
+
+    /// Username/password pair used for basic auth: the API key when one is
+    /// configured, otherwise the account SID and auth token.
+    pub fn credentials(&self) -> (&str, &str) {
+        match &self.api_key {
+            Some(key) => (&key.sid, &key.secret),
+            None => (&self.account_sid, &self.auth_token),
+        }
+    }
+
+    /// Starts an authenticated request against any Twilio API host.
+    pub fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        let (username, password) = self.credentials();
+        Client::new()
+            .request(method, url)
+            .basic_auth(username, Some(password))
+    }
+}
+
+/// Sends a request and deserializes the JSON body, turning non-2xx responses
+/// into the crate's usual `Twilio API error` message.
+pub(crate) async fn send_json<T: DeserializeOwned>(
+    request: RequestBuilder,
+) -> Result<T, Box<dyn Error>> {
+    let response = check_status(request.send().await?).await?;
+    Ok(response.json::<T>().await?)
+}
+
+/// Sends a request whose response has no body worth reading (e.g. DELETE).
+pub(crate) async fn send_empty(request: RequestBuilder) -> Result<(), Box<dyn Error>> {
+    check_status(request.send().await?).await?;
+    Ok(())
+}
+
+/// A non-2xx response from a Twilio API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwilioApiError {
+    pub status: reqwest::StatusCode,
+    pub body: String,
+}
+
+impl TwilioApiError {
+    /// 4xx: Twilio rejected the request, so nothing was sent or changed.
+    pub fn is_client_error(&self) -> bool {
+        self.status.is_client_error()
+    }
+}
+
+impl fmt::Display for TwilioApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Twilio API error {}: {}", self.status, self.body)
+    }
+}
+
+impl Error for TwilioApiError {}
+
+pub(crate) async fn check_status(response: Response) -> Result<Response, Box<dyn Error>> {
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await?;
+        return Err(Box::new(TwilioApiError { status, body }));
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_key_secrets_are_not_printed() {
+        let credentials = ApiKeyCredentials {
+            sid: "SK123".to_string(),
+            secret: "hunter2".to_string(),
+        };
+        let printed = format!("{:?}", credentials);
+        assert!(printed.contains("SK123"), "{}", printed);
+        assert!(!printed.contains("hunter2"), "{}", printed);
+    }
 }
@@ -0,0 +1,521 @@
+use crate::client::{send_empty, send_json, TwilioClient};
+use crate::voice::call_address;
+use reqwest::Method;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConferenceStatus {
+    /// Created but waiting for a participant with `startConferenceOnEnter`.
+    Init,
+    InProgress,
+    Completed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ConferenceStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConferenceStatus::Init => "init",
+            ConferenceStatus::InProgress => "in-progress",
+            ConferenceStatus::Completed => "completed",
+            ConferenceStatus::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConferenceResource {
+    pub sid: String,
+    pub account_sid: String,
+    pub friendly_name: String,
+    pub status: ConferenceStatus,
+    pub region: Option<String>,
+    pub reason_conference_ended: Option<String>,
+    pub call_sid_ending_conference: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ConferencePage {
+    conferences: Vec<ConferenceResource>,
+}
+
+/// Filters for `TwilioClient::list_conferences`; unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct ConferenceFilter {
+    pub friendly_name: Option<String>,
+    pub status: Option<ConferenceStatus>,
+    /// `YYYY-MM-DD`; only conferences created on this day.
+    pub date_created: Option<String>,
+    pub page_size: Option<u32>,
+}
+
+/// Changes applied by `TwilioClient::update_conference`.
+#[derive(Debug, Clone, Default)]
+pub struct ConferenceUpdate {
+    /// Setting `Completed` ends the conference and disconnects everyone.
+    pub status: Option<ConferenceStatus>,
+    /// TwiML (`<Play>`/`<Say>`) announced to all participants.
+    pub announce_url: Option<String>,
+    pub announce_method: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum ParticipantStatus {
+    Queued,
+    Connecting,
+    Ringing,
+    Connected,
+    Complete,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ParticipantResource {
+    pub account_sid: String,
+    pub conference_sid: String,
+    pub call_sid: String,
+    pub label: Option<String>,
+    pub status: ParticipantStatus,
+    pub muted: bool,
+    pub hold: bool,
+    #[serde(default)]
+    pub coaching: bool,
+    pub call_sid_to_coach: Option<String>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ParticipantPage {
+    participants: Vec<ParticipantResource>,
+}
+
+/// Events reported to a conference's `statusCallback`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConferenceEvent {
+    ConferenceStart,
+    ConferenceEnd,
+    ParticipantJoin,
+    ParticipantLeave,
+    ParticipantMute,
+    ParticipantUnmute,
+    ParticipantHold,
+    ParticipantUnhold,
+    ParticipantModify,
+    ParticipantSpeechStart,
+    ParticipantSpeechStop,
+    AnnouncementEnd,
+    AnnouncementFail,
+    Unknown,
+}
+
+impl ConferenceEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConferenceEvent::ConferenceStart => "conference-start",
+            ConferenceEvent::ConferenceEnd => "conference-end",
+            ConferenceEvent::ParticipantJoin => "participant-join",
+            ConferenceEvent::ParticipantLeave => "participant-leave",
+            ConferenceEvent::ParticipantMute => "participant-mute",
+            ConferenceEvent::ParticipantUnmute => "participant-unmute",
+            ConferenceEvent::ParticipantHold => "participant-hold",
+            ConferenceEvent::ParticipantUnhold => "participant-unhold",
+            ConferenceEvent::ParticipantModify => "participant-modify",
+            ConferenceEvent::ParticipantSpeechStart => "participant-speech-start",
+            ConferenceEvent::ParticipantSpeechStop => "participant-speech-stop",
+            ConferenceEvent::AnnouncementEnd => "announcement-end",
+            ConferenceEvent::AnnouncementFail => "announcement-fail",
+            ConferenceEvent::Unknown => "unknown",
+        }
+    }
+
+    /// Value for `statusCallbackEvent`, which uses the short names
+    /// (`start`, `join`, `mute`, ...) rather than the reported ones.
+    pub fn subscription_name(&self) -> &'static str {
+        match self {
+            ConferenceEvent::ConferenceStart => "start",
+            ConferenceEvent::ConferenceEnd => "end",
+            ConferenceEvent::ParticipantJoin => "join",
+            ConferenceEvent::ParticipantLeave => "leave",
+            ConferenceEvent::ParticipantMute | ConferenceEvent::ParticipantUnmute => "mute",
+            ConferenceEvent::ParticipantHold | ConferenceEvent::ParticipantUnhold => "hold",
+            ConferenceEvent::ParticipantModify => "modify",
+            ConferenceEvent::ParticipantSpeechStart | ConferenceEvent::ParticipantSpeechStop => {
+                "speaker"
+            }
+            ConferenceEvent::AnnouncementEnd | ConferenceEvent::AnnouncementFail => "announcement",
+            ConferenceEvent::Unknown => "",
+        }
+    }
+}
+
+impl From<&str> for ConferenceEvent {
+    fn from(event: &str) -> Self {
+        match event {
+            "conference-start" => ConferenceEvent::ConferenceStart,
+            "conference-end" => ConferenceEvent::ConferenceEnd,
+            "participant-join" => ConferenceEvent::ParticipantJoin,
+            "participant-leave" => ConferenceEvent::ParticipantLeave,
+            "participant-mute" => ConferenceEvent::ParticipantMute,
+            "participant-unmute" => ConferenceEvent::ParticipantUnmute,
+            "participant-hold" => ConferenceEvent::ParticipantHold,
+            "participant-unhold" => ConferenceEvent::ParticipantUnhold,
+            "participant-modify" => ConferenceEvent::ParticipantModify,
+            "participant-speech-start" => ConferenceEvent::ParticipantSpeechStart,
+            "participant-speech-stop" => ConferenceEvent::ParticipantSpeechStop,
+            "announcement-end" => ConferenceEvent::AnnouncementEnd,
+            "announcement-fail" => ConferenceEvent::AnnouncementFail,
+            _ => ConferenceEvent::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for ConferenceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parameters for `TwilioClient::add_participant`, which dials `to` and
+/// joins them to the conference once they answer.
+#[derive(Debug, Clone)]
+pub struct AddParticipantRequest {
+    /// Phone number, `client:identity` or `sip:` URI to dial.
+    pub to: String,
+    /// Defaults to the client's `from_phone`.
+    pub from: Option<String>,
+    /// Name for addressing the participant instead of its call SID.
+    pub label: Option<String>,
+    pub muted: bool,
+    /// `true`, `false`, `onEnter` or `onExit`.
+    pub beep: Option<String>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    pub wait_url: Option<String>,
+    /// Seconds to let the call ring before giving up.
+    pub timeout: Option<u32>,
+    pub record: bool,
+    /// Call SID of the participant this one coaches; only they hear the coach.
+    pub call_sid_to_coach: Option<String>,
+    /// Status callback for the participant's outbound call.
+    pub status_callback: Option<String>,
+    /// Status callback for the conference, used if this participant starts it.
+    pub conference_status_callback: Option<String>,
+    pub conference_status_callback_events: Vec<ConferenceEvent>,
+}
+
+impl AddParticipantRequest {
+    pub fn new(to: &str) -> Self {
+        Self {
+            to: to.to_string(),
+            from: None,
+            label: None,
+            muted: false,
+            beep: None,
+            start_conference_on_enter: None,
+            end_conference_on_exit: None,
+            wait_url: None,
+            timeout: None,
+            record: false,
+            call_sid_to_coach: None,
+            status_callback: None,
+            conference_status_callback: None,
+            conference_status_callback_events: Vec::new(),
+        }
+    }
+}
+
+/// Changes applied by `TwilioClient::update_participant`.
+#[derive(Debug, Clone, Default)]
+pub struct ParticipantUpdate {
+    pub muted: Option<bool>,
+    pub hold: Option<bool>,
+    /// Music or TwiML played while on hold.
+    pub hold_url: Option<String>,
+    pub hold_method: Option<String>,
+    /// Call SID to coach; `Some("")` stops coaching.
+    pub call_sid_to_coach: Option<String>,
+    /// TwiML announced to this participant only.
+    pub announce_url: Option<String>,
+}
+
+/// Participant SID or label as used in participant URLs.
+fn participant_url(client: &TwilioClient, conference_sid: &str, participant: &str) -> String {
+    client.api_url(&format!(
+        "Conferences/{}/Participants/{}.json",
+        conference_sid,
+        urlencoding::encode(participant)
+    ))
+}
+
+fn flag(value: bool) -> String {
+    value.to_string()
+}
+
+impl TwilioClient {
+    pub async fn list_conferences(
+        &self,
+        filter: &ConferenceFilter,
+    ) -> Result<Vec<ConferenceResource>, Box<dyn Error>> {
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(name) = &filter.friendly_name {
+            query.push(("FriendlyName", name.clone()));
+        }
+        if let Some(status) = filter.status {
+            query.push(("Status", status.as_str().to_string()));
+        }
+        if let Some(date) = &filter.date_created {
+            query.push(("DateCreated", date.clone()));
+        }
+        if let Some(page_size) = filter.page_size {
+            query.push(("PageSize", page_size.to_string()));
+        }
+
+        let page: ConferencePage = send_json(
+            self.request(Method::GET, &self.api_url("Conferences.json"))
+                .query(&query),
+        )
+        .await?;
+        Ok(page.conferences)
+    }
+
+    pub async fn fetch_conference(
+        &self,
+        conference_sid: &str,
+    ) -> Result<ConferenceResource, Box<dyn Error>> {
+        let url = self.api_url(&format!("Conferences/{}.json", conference_sid));
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    pub async fn update_conference(
+        &self,
+        conference_sid: &str,
+        update: &ConferenceUpdate,
+    ) -> Result<ConferenceResource, Box<dyn Error>> {
+        let mut params: Vec<(&str, String)> = Vec::new();
+        if let Some(status) = update.status {
+            params.push(("Status", status.as_str().to_string()));
+        }
+        if let Some(url) = &update.announce_url {
+            params.push(("AnnounceUrl", url.clone()));
+        }
+        if let Some(method) = &update.announce_method {
+            params.push(("AnnounceMethod", method.clone()));
+        }
+
+        let url = self.api_url(&format!("Conferences/{}.json", conference_sid));
+        send_json(self.request(Method::POST, &url).form(&params)).await
+    }
+
+    /// Ends the conference, disconnecting every participant.
+    pub async fn end_conference(
+        &self,
+        conference_sid: &str,
+    ) -> Result<ConferenceResource, Box<dyn Error>> {
+        let update = ConferenceUpdate {
+            status: Some(ConferenceStatus::Completed),
+            ..Default::default()
+        };
+        self.update_conference(conference_sid, &update).await
+    }
+
+    pub async fn list_participants(
+        &self,
+        conference_sid: &str,
+    ) -> Result<Vec<ParticipantResource>, Box<dyn Error>> {
+        let url = self.api_url(&format!("Conferences/{}/Participants.json", conference_sid));
+        let page: ParticipantPage = send_json(self.request(Method::GET, &url)).await?;
+        Ok(page.participants)
+    }
+
+    /// Fetches a participant by call SID or label.
+    pub async fn fetch_participant(
+        &self,
+        conference_sid: &str,
+        participant: &str,
+    ) -> Result<ParticipantResource, Box<dyn Error>> {
+        let url = participant_url(self, conference_sid, participant);
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    /// Dials out and adds the callee to the conference. `conference` may be
+    /// a conference SID or a friendly name; a name creates the conference if
+    /// it does not exist yet.
+    pub async fn add_participant(
+        &self,
+        conference: &str,
+        request: &AddParticipantRequest,
+    ) -> Result<ParticipantResource, Box<dyn Error>> {
+        let from = match &request.from {
+            Some(from) => call_address(self, from)?,
+            None => self.from_number()?.e164(),
+        };
+
+        let mut params: Vec<(&str, String)> =
+            vec![("From", from), ("To", call_address(self, &request.to)?)];
+        if let Some(label) = &request.label {
+            params.push(("Label", label.clone()));
+        }
+        if request.muted {
+            params.push(("Muted", flag(true)));
+        }
+        if let Some(beep) = &request.beep {
+            params.push(("Beep", beep.clone()));
+        }
+        if let Some(start) = request.start_conference_on_enter {
+            params.push(("StartConferenceOnEnter", flag(start)));
+        }
+        if let Some(end) = request.end_conference_on_exit {
+            params.push(("EndConferenceOnExit", flag(end)));
+        }
+        if let Some(wait_url) = &request.wait_url {
+            params.push(("WaitUrl", wait_url.clone()));
+        }
+        if let Some(timeout) = request.timeout {
+            params.push(("Timeout", timeout.to_string()));
+        }
+        if request.record {
+            params.push(("Record", flag(true)));
+        }
+        if let Some(coached) = &request.call_sid_to_coach {
+            params.push(("Coaching", flag(true)));
+            params.push(("CallSidToCoach", coached.clone()));
+        }
+        if let Some(callback) = &request.status_callback {
+            params.push(("StatusCallback", callback.clone()));
+        }
+        if let Some(callback) = &request.conference_status_callback {
+            params.push(("ConferenceStatusCallback", callback.clone()));
+        }
+        if !request.conference_status_callback_events.is_empty() {
+            let mut events: Vec<&str> = Vec::new();
+            for event in &request.conference_status_callback_events {
+                let name = event.subscription_name();
+                if !name.is_empty() && !events.contains(&name) {
+                    events.push(name);
+                }
+            }
+            params.push(("ConferenceStatusCallbackEvent", events.join(" ")));
+        }
+
+        let url = self.api_url(&format!(
+            "Conferences/{}/Participants.json",
+            urlencoding::encode(conference)
+        ));
+        send_json(self.request(Method::POST, &url).form(&params)).await
+    }
+
+    pub async fn update_participant(
+        &self,
+        conference_sid: &str,
+        participant: &str,
+        update: &ParticipantUpdate,
+    ) -> Result<ParticipantResource, Box<dyn Error>> {
+        let mut params: Vec<(&str, String)> = Vec::new();
+        if let Some(muted) = update.muted {
+            params.push(("Muted", flag(muted)));
+        }
+        if let Some(hold) = update.hold {
+            params.push(("Hold", flag(hold)));
+        }
+        if let Some(url) = &update.hold_url {
+            params.push(("HoldUrl", url.clone()));
+        }
+        if let Some(method) = &update.hold_method {
+            params.push(("HoldMethod", method.clone()));
+        }
+        if let Some(coached) = &update.call_sid_to_coach {
+            params.push(("Coaching", flag(!coached.is_empty())));
+            if !coached.is_empty() {
+                params.push(("CallSidToCoach", coached.clone()));
+            }
+        }
+        if let Some(url) = &update.announce_url {
+            params.push(("AnnounceUrl", url.clone()));
+        }
+
+        let url = participant_url(self, conference_sid, participant);
+        send_json(self.request(Method::POST, &url).form(&params)).await
+    }
+
+    pub async fn mute_participant(
+        &self,
+        conference_sid: &str,
+        participant: &str,
+        muted: bool,
+    ) -> Result<ParticipantResource, Box<dyn Error>> {
+        let update = ParticipantUpdate {
+            muted: Some(muted),
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, participant, &update)
+            .await
+    }
+
+    /// Puts a participant on hold, optionally playing `hold_music_url`.
+    pub async fn hold_participant(
+        &self,
+        conference_sid: &str,
+        participant: &str,
+        hold_music_url: Option<&str>,
+    ) -> Result<ParticipantResource, Box<dyn Error>> {
+        let update = ParticipantUpdate {
+            hold: Some(true),
+            hold_url: hold_music_url.map(str::to_string),
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, participant, &update)
+            .await
+    }
+
+    pub async fn unhold_participant(
+        &self,
+        conference_sid: &str,
+        participant: &str,
+    ) -> Result<ParticipantResource, Box<dyn Error>> {
+        let update = ParticipantUpdate {
+            hold: Some(false),
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, participant, &update)
+            .await
+    }
+
+    /// Makes `participant` a coach heard only by `call_sid_to_coach`.
+    pub async fn coach_participant(
+        &self,
+        conference_sid: &str,
+        participant: &str,
+        call_sid_to_coach: &str,
+    ) -> Result<ParticipantResource, Box<dyn Error>> {
+        let update = ParticipantUpdate {
+            call_sid_to_coach: Some(call_sid_to_coach.to_string()),
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, participant, &update)
+            .await
+    }
+
+    /// Removes a participant from the conference, hanging up their call.
+    pub async fn kick_participant(
+        &self,
+        conference_sid: &str,
+        participant: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let url = participant_url(self, conference_sid, participant);
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+}
@@ -0,0 +1,955 @@
+use crate::client::{send_empty, send_json, TwilioClient};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub(crate) const CONTENT_API: &str = "https://content.twilio.com/v1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentError {
+    /// A template needs at least one content type.
+    NoTypes,
+    /// `{{name}}` is used in the template but has no sample value in
+    /// `variables`.
+    UndefinedVariable(String),
+    /// WhatsApp template names may only contain lowercase letters, digits
+    /// and underscores.
+    InvalidTemplateName(String),
+    /// The template uses `{{name}}` but no value was supplied for it.
+    MissingVariable(String),
+    /// A value was supplied for a variable the template does not use.
+    UnexpectedVariable(String),
+    /// Twilio rejects variables whose value is empty.
+    EmptyVariable(String),
+    /// No template with this name exists in the requested language or any
+    /// of its fallbacks.
+    TemplateNotFound { name: String, language: String },
+    /// The template has not been approved by WhatsApp for
+    /// business-initiated messages.
+    NotApproved {
+        content_sid: String,
+        status: ApprovalStatus,
+        rejection_reason: Option<String>,
+    },
+}
+
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContentError::NoTypes => write!(f, "Content template has no content types"),
+            ContentError::UndefinedVariable(name) => write!(
+                f,
+                "Content template uses {{{{{}}}}} but does not define a sample value for it",
+                name
+            ),
+            ContentError::InvalidTemplateName(name) => write!(
+                f,
+                "'{}' is not a valid WhatsApp template name (use lowercase letters, digits and underscores)",
+                name
+            ),
+            ContentError::MissingVariable(name) => {
+                write!(f, "No value supplied for content variable {{{{{}}}}}", name)
+            }
+            ContentError::UnexpectedVariable(name) => write!(
+                f,
+                "Content variable '{}' is not used by the template",
+                name
+            ),
+            ContentError::EmptyVariable(name) => {
+                write!(f, "Content variable '{}' has an empty value", name)
+            }
+            ContentError::TemplateNotFound { name, language } => write!(
+                f,
+                "No content template named '{}' for language '{}'",
+                name, language
+            ),
+            ContentError::NotApproved {
+                content_sid,
+                status,
+                rejection_reason,
+            } => {
+                write!(
+                    f,
+                    "WhatsApp template {} is not approved (status: {})",
+                    content_sid, status
+                )?;
+                if let Some(reason) = rejection_reason {
+                    write!(f, ": {}", reason)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for ContentError {}
+
+/// `twilio/text`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TextContent {
+    pub body: String,
+}
+
+/// `twilio/media`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MediaContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub media: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QuickReplyAction {
+    pub title: String,
+    pub id: String,
+}
+
+/// `twilio/quick-reply`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QuickReplyContent {
+    pub body: String,
+    pub actions: Vec<QuickReplyAction>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CallToAction {
+    Url { title: String, url: String },
+    PhoneNumber { title: String, phone: String },
+    CopyCode { title: String, code: String },
+    VoiceCall { title: String },
+}
+
+/// `twilio/call-to-action`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CallToActionContent {
+    pub body: String,
+    pub actions: Vec<CallToAction>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ListItem {
+    pub id: String,
+    pub item: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// `twilio/list-picker`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ListPickerContent {
+    pub body: String,
+    /// Label of the button that opens the list.
+    pub button: String,
+    pub items: Vec<ListItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CardAction {
+    QuickReply { title: String, id: String },
+    Url { title: String, url: String },
+    PhoneNumber { title: String, phone: String },
+    CopyCode { title: String, code: String },
+}
+
+/// `twilio/card`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CardContent {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub media: Vec<String>,
+    #[serde(default)]
+    pub actions: Vec<CardAction>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CatalogItem {
+    /// Product retailer ID in the Meta catalog.
+    pub id: String,
+    /// Required for multi-product messages; omitted for a single product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section_title: Option<String>,
+}
+
+/// `twilio/catalog`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CatalogContent {
+    /// Meta catalog ID.
+    pub id: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_item_id: Option<String>,
+    #[serde(default)]
+    pub items: Vec<CatalogItem>,
+    /// Variable that supplies the items at send time instead of `items`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dynamic_items: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuthenticationAction {
+    CopyCode { copy_code_text: String },
+}
+
+/// `whatsapp/authentication` (one-time passcodes)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WhatsAppAuthenticationContent {
+    /// Appends "For your security, do not share this code."
+    pub add_security_recommendation: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_expiration_minutes: Option<u32>,
+    pub actions: Vec<AuthenticationAction>,
+}
+
+/// The representations of one template; Twilio picks the richest one the
+/// recipient's channel supports.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentTypes {
+    #[serde(rename = "twilio/text", skip_serializing_if = "Option::is_none")]
+    pub text: Option<TextContent>,
+    #[serde(rename = "twilio/media", skip_serializing_if = "Option::is_none")]
+    pub media: Option<MediaContent>,
+    #[serde(rename = "twilio/quick-reply", skip_serializing_if = "Option::is_none")]
+    pub quick_reply: Option<QuickReplyContent>,
+    #[serde(
+        rename = "twilio/call-to-action",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub call_to_action: Option<CallToActionContent>,
+    #[serde(rename = "twilio/list-picker", skip_serializing_if = "Option::is_none")]
+    pub list_picker: Option<ListPickerContent>,
+    #[serde(rename = "twilio/card", skip_serializing_if = "Option::is_none")]
+    pub card: Option<CardContent>,
+    #[serde(rename = "twilio/catalog", skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<CatalogContent>,
+    #[serde(
+        rename = "whatsapp/authentication",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub whatsapp_authentication: Option<WhatsAppAuthenticationContent>,
+}
+
+impl ContentTypes {
+    pub fn is_empty(&self) -> bool {
+        *self == ContentTypes::default()
+    }
+
+    /// Every piece of text in the template that may contain `{{variables}}`.
+    fn texts(&self) -> Vec<&str> {
+        let mut texts: Vec<&str> = Vec::new();
+        if let Some(text) = &self.text {
+            texts.push(&text.body);
+        }
+        if let Some(media) = &self.media {
+            texts.extend(media.body.as_deref());
+            texts.extend(media.media.iter().map(String::as_str));
+        }
+        if let Some(quick_reply) = &self.quick_reply {
+            texts.push(&quick_reply.body);
+            for action in &quick_reply.actions {
+                texts.push(&action.title);
+                texts.push(&action.id);
+            }
+        }
+        if let Some(cta) = &self.call_to_action {
+            texts.push(&cta.body);
+            for action in &cta.actions {
+                if let CallToAction::Url { url, .. } = action {
+                    texts.push(url);
+                }
+            }
+        }
+        if let Some(list) = &self.list_picker {
+            texts.push(&list.body);
+            for item in &list.items {
+                texts.push(&item.item);
+                texts.push(&item.id);
+                texts.extend(item.description.as_deref());
+            }
+        }
+        if let Some(card) = &self.card {
+            texts.push(&card.title);
+            texts.extend(card.subtitle.as_deref());
+            texts.extend(card.media.iter().map(String::as_str));
+            for action in &card.actions {
+                if let CardAction::Url { url, .. } = action {
+                    texts.push(url);
+                }
+            }
+        }
+        if let Some(catalog) = &self.catalog {
+            texts.push(&catalog.body);
+            texts.extend(catalog.title.as_deref());
+            texts.extend(catalog.subtitle.as_deref());
+            texts.extend(catalog.dynamic_items.as_deref());
+        }
+        texts
+    }
+
+    /// Names of all `{{placeholders}}` used by the template, in order of
+    /// first use.
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for text in self.texts() {
+            for name in placeholders(text) {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+}
+
+/// Extracts the names of `{{name}}` placeholders from `text`.
+pub fn placeholders(text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                names.push(after[..end].trim().to_string());
+                rest = &after[end + 2..];
+            }
+            None => break,
+        }
+    }
+    names
+}
+
+/// Values for a template's `{{1}}`, `{{2}}`... placeholders, sent as the
+/// `ContentVariables` JSON object.
+///
+/// # Example
+/// ```
+/// use twilio_rs::content::ContentVariables;
+///
+/// let variables = ContentVariables::new().push("12/1").push("3pm");
+/// assert_eq!(variables.to_json(), r#"{"1":"12/1","2":"3pm"}"#);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct ContentVariables {
+    values: BTreeMap<String, String>,
+}
+
+impl ContentVariables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Numbers `values` as `"1"`, `"2"`, ... in order.
+    pub fn from_values<I, V>(values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        values
+            .into_iter()
+            .fold(Self::new(), |variables, value| variables.push(value))
+    }
+
+    /// Sets the value of the named (usually numeric) variable.
+    pub fn set(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    /// Adds `value` under the next unused number, starting from `"1"`.
+    pub fn push(self, value: impl Into<String>) -> Self {
+        let next = (1..)
+            .find(|n: &usize| !self.values.contains_key(&n.to_string()))
+            .unwrap_or_default();
+        self.set(next.to_string(), value)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.values).expect("string maps always serialize")
+    }
+
+    /// Checks the values against the placeholders `template` declares:
+    /// every placeholder needs a non-empty value and no extra values are
+    /// allowed.
+    pub fn validate_against(&self, template: &ContentTemplate) -> Result<(), ContentError> {
+        let mut declared = template.types.placeholders();
+        for name in template.variables.keys() {
+            if !declared.contains(name) {
+                declared.push(name.clone());
+            }
+        }
+
+        for name in &declared {
+            match self.get(name) {
+                None => return Err(ContentError::MissingVariable(name.clone())),
+                Some(value) if value.trim().is_empty() => {
+                    return Err(ContentError::EmptyVariable(name.clone()))
+                }
+                Some(_) => {}
+            }
+        }
+        for (name, _) in self.iter() {
+            if !declared.iter().any(|declared| declared == name) {
+                return Err(ContentError::UnexpectedVariable(name.to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for ContentVariables {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |variables, (name, value)| {
+                variables.set(name, value)
+            })
+    }
+}
+
+/// A template to create with `TwilioClient::create_content`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct NewContent {
+    pub friendly_name: String,
+    /// Language code such as `en` or `pt_BR`.
+    pub language: String,
+    /// Sample value for each placeholder, keyed by its name (`"1"`, `"2"`...).
+    pub variables: BTreeMap<String, String>,
+    pub types: ContentTypes,
+}
+
+impl NewContent {
+    pub fn new(friendly_name: &str, language: &str, types: ContentTypes) -> Self {
+        Self {
+            friendly_name: friendly_name.to_string(),
+            language: language.to_string(),
+            variables: BTreeMap::new(),
+            types,
+        }
+    }
+
+    pub fn variable(mut self, name: &str, sample: &str) -> Self {
+        self.variables.insert(name.to_string(), sample.to_string());
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ContentError> {
+        if self.types.is_empty() {
+            return Err(ContentError::NoTypes);
+        }
+        for name in self.types.placeholders() {
+            if !self.variables.contains_key(&name) {
+                return Err(ContentError::UndefinedVariable(name));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ContentTemplate {
+    pub sid: String,
+    pub account_sid: String,
+    pub friendly_name: Option<String>,
+    pub language: String,
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    #[serde(default)]
+    pub types: ContentTypes,
+    pub url: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TemplateCategory {
+    Utility,
+    Marketing,
+    Authentication,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalStatus {
+    Unsubmitted,
+    Received,
+    Pending,
+    Approved,
+    Rejected,
+    Paused,
+    Disabled,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ApprovalStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApprovalStatus::Unsubmitted => "unsubmitted",
+            ApprovalStatus::Received => "received",
+            ApprovalStatus::Pending => "pending",
+            ApprovalStatus::Approved => "approved",
+            ApprovalStatus::Rejected => "rejected",
+            ApprovalStatus::Paused => "paused",
+            ApprovalStatus::Disabled => "disabled",
+            ApprovalStatus::Unknown => "unknown",
+        }
+    }
+
+    /// Whether WhatsApp is still reviewing the template.
+    pub fn is_in_review(&self) -> bool {
+        matches!(self, ApprovalStatus::Received | ApprovalStatus::Pending)
+    }
+}
+
+impl fmt::Display for ApprovalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// WhatsApp's review of a content template.
+#[derive(Deserialize, Debug, Clone)]
+pub struct WhatsAppApproval {
+    pub name: Option<String>,
+    /// Category WhatsApp assigned, which may differ from the one requested.
+    pub category: Option<String>,
+    pub status: ApprovalStatus,
+    pub rejection_reason: Option<String>,
+    pub content_type: Option<String>,
+    #[serde(default)]
+    pub allow_category_change: bool,
+}
+
+impl WhatsAppApproval {
+    pub fn is_approved(&self) -> bool {
+        self.status == ApprovalStatus::Approved
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ApprovalRequests {
+    whatsapp: Option<WhatsAppApproval>,
+}
+
+#[derive(Serialize, Debug)]
+struct WhatsAppApprovalSubmission<'a> {
+    name: &'a str,
+    category: TemplateCategory,
+}
+
+fn validate_template_name(name: &str) -> Result<(), ContentError> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(ContentError::InvalidTemplateName(name.to_string()))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ContentPageMeta {
+    next_page_url: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ContentPage {
+    contents: Vec<ContentTemplate>,
+    meta: Option<ContentPageMeta>,
+}
+
+/// Language used when a template exists in neither the requested language
+/// nor another variant of it.
+pub const FALLBACK_LANGUAGE: &str = "en";
+
+fn normalize_language(language: &str) -> String {
+    language.trim().replace('-', "_").to_lowercase()
+}
+
+fn base_language(language: &str) -> &str {
+    language.split('_').next().unwrap_or(language)
+}
+
+/// Picks the best language variant among `templates`: the exact locale,
+/// then its base language (`pt_BR` → `pt`), then any other variant of the
+/// base language, then the same steps for `FALLBACK_LANGUAGE`.
+pub fn pick_language<'a>(
+    templates: &[&'a ContentTemplate],
+    language: &str,
+) -> Option<&'a ContentTemplate> {
+    let find = |language: &str| {
+        let wanted = normalize_language(language);
+        let base = base_language(&wanted).to_string();
+        let languages: Vec<String> = templates
+            .iter()
+            .map(|template| normalize_language(&template.language))
+            .collect();
+
+        languages
+            .iter()
+            .position(|candidate| *candidate == wanted)
+            .or_else(|| languages.iter().position(|candidate| *candidate == base))
+            .or_else(|| {
+                languages
+                    .iter()
+                    .position(|candidate| base_language(candidate) == base)
+            })
+            .map(|index| templates[index])
+    };
+    find(language).or_else(|| find(FALLBACK_LANGUAGE))
+}
+
+/// Prefix of the friendly name given to content created by
+/// `TwilioClient::ephemeral_content`.
+pub const EPHEMERAL_PREFIX: &str = "twilio_rs_";
+
+/// Hex SHA-256 of the language and types, identifying identical content.
+pub fn content_hash(language: &str, types: &ContentTypes) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(language.as_bytes());
+    hasher.update([0]);
+    hasher.update(serde_json::to_vec(types).unwrap_or_default());
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// How long a content listing is trusted for names it does not contain, or
+/// languages it only has a fallback for, before the Content API is listed
+/// again.
+pub const CONTENT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Debug)]
+struct CachedTemplates {
+    fetched_at: Instant,
+    templates: Vec<ContentTemplate>,
+}
+
+/// Content templates fetched from the Content API, shared between clones of
+/// a `TwilioClient` so name lookups do not list templates on every send.
+/// Also remembers the SID of each piece of ephemeral content by its hash.
+#[derive(Clone, Debug, Default)]
+pub struct ContentCache {
+    templates: Arc<Mutex<Option<CachedTemplates>>>,
+    ephemeral: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl ContentCache {
+    /// Forgets the cached templates and ephemeral content SIDs; the next
+    /// lookup fetches them again.
+    pub fn clear(&self) {
+        *self.templates.lock().unwrap() = None;
+        self.ephemeral.lock().unwrap().clear();
+    }
+
+    fn ephemeral_sid(&self, hash: &str) -> Option<String> {
+        self.ephemeral.lock().unwrap().get(hash).cloned()
+    }
+
+    fn store_ephemeral(&self, hash: &str, content_sid: &str) {
+        self.ephemeral
+            .lock()
+            .unwrap()
+            .insert(hash.to_string(), content_sid.to_string());
+    }
+
+    fn store(&self, templates: Vec<ContentTemplate>) {
+        *self.templates.lock().unwrap() = Some(CachedTemplates {
+            fetched_at: Instant::now(),
+            templates,
+        });
+    }
+
+    /// Whether a listing was stored less than `CONTENT_CACHE_TTL` ago.
+    fn is_fresh(&self) -> bool {
+        self.templates
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|cached| cached.fetched_at.elapsed() < CONTENT_CACHE_TTL)
+    }
+
+    /// The best cached match for `name` in `language`, if any.
+    fn lookup(&self, name: &str, language: &str) -> Option<ContentTemplate> {
+        let cached = self.templates.lock().unwrap();
+        let named: Vec<&ContentTemplate> = cached
+            .as_ref()?
+            .templates
+            .iter()
+            .filter(|template| template.friendly_name.as_deref() == Some(name))
+            .collect();
+        pick_language(&named, language).cloned()
+    }
+}
+
+impl TwilioClient {
+    pub async fn create_content(
+        &self,
+        content: &NewContent,
+    ) -> Result<ContentTemplate, Box<dyn Error>> {
+        content.validate()?;
+        let url = format!("{}/Content", CONTENT_API);
+        send_json(self.request(Method::POST, &url).json(content)).await
+    }
+
+    /// Lists every content template in the account, following pagination.
+    pub async fn list_content(&self) -> Result<Vec<ContentTemplate>, Box<dyn Error>> {
+        let mut url = format!("{}/Content?PageSize=500", CONTENT_API);
+        let mut contents = Vec::new();
+        loop {
+            let page: ContentPage = send_json(self.request(Method::GET, &url)).await?;
+            contents.extend(page.contents);
+            match page.meta.and_then(|meta| meta.next_page_url) {
+                Some(next) => url = next,
+                None => return Ok(contents),
+            }
+        }
+    }
+
+    /// Finds the template called `name` in `language`, falling back as
+    /// described in `pick_language`. Lookups use `content_cache`; a miss or a
+    /// fallback-language hit lists the templates again once the cache is
+    /// older than `CONTENT_CACHE_TTL`, so new templates and locales are
+    /// picked up without listing on every send.
+    pub async fn resolve_template(
+        &self,
+        name: &str,
+        language: &str,
+    ) -> Result<ContentTemplate, Box<dyn Error>> {
+        let cached = self.content_cache.lookup(name, language);
+        let exact = cached.as_ref().is_some_and(|template| {
+            normalize_language(&template.language) == normalize_language(language)
+        });
+        let template = if exact || self.content_cache.is_fresh() {
+            cached
+        } else {
+            self.content_cache.store(self.list_content().await?);
+            self.content_cache.lookup(name, language)
+        };
+        template.ok_or_else(|| {
+            Box::new(ContentError::TemplateNotFound {
+                name: name.to_string(),
+                language: language.to_string(),
+            }) as Box<dyn Error>
+        })
+    }
+
+    /// Returns the SID of content with exactly these `types`, creating it on
+    /// first use. Content is keyed by `content_hash`, so identical messages
+    /// reuse one template. Before creating, the account's content list is
+    /// checked (at most once per `CONTENT_CACHE_TTL`), so templates made by
+    /// another process or before a restart are reused too.
+    ///
+    /// Per-message values belong in `{{n}}` placeholders sent as content
+    /// variables, not in `types`, or every message creates a new permanent
+    /// template. `samples` supplies the example value Twilio requires for
+    /// each placeholder. Remove unused templates with
+    /// `delete_ephemeral_content`.
+    pub async fn ephemeral_content(
+        &self,
+        language: &str,
+        types: ContentTypes,
+        samples: Option<&ContentVariables>,
+    ) -> Result<String, Box<dyn Error>> {
+        let hash = content_hash(language, &types);
+        if let Some(content_sid) = self.content_cache.ephemeral_sid(&hash) {
+            return Ok(content_sid);
+        }
+
+        let name = format!("{}{}", EPHEMERAL_PREFIX, &hash[..32]);
+        let mut existing = self.content_cache.lookup(&name, language);
+        if existing.is_none() && !self.content_cache.is_fresh() {
+            self.content_cache.store(self.list_content().await?);
+            existing = self.content_cache.lookup(&name, language);
+        }
+
+        let content_sid = match existing {
+            Some(template) => template.sid,
+            None => {
+                let mut content = NewContent::new(&name, language, types);
+                for (name, sample) in samples.into_iter().flat_map(ContentVariables::iter) {
+                    content = content.variable(name, sample);
+                }
+                self.create_content(&content).await?.sid
+            }
+        };
+        self.content_cache.store_ephemeral(&hash, &content_sid);
+        Ok(content_sid)
+    }
+
+    /// Deletes every template created by `ephemeral_content` and forgets
+    /// them, returning how many were deleted. Messages already sent are not
+    /// affected; later sends recreate the templates they need.
+    pub async fn delete_ephemeral_content(&self) -> Result<usize, Box<dyn Error>> {
+        let ephemeral: Vec<ContentTemplate> = self
+            .list_content()
+            .await?
+            .into_iter()
+            .filter(|template| {
+                template
+                    .friendly_name
+                    .as_deref()
+                    .is_some_and(|name| name.starts_with(EPHEMERAL_PREFIX))
+            })
+            .collect();
+        for template in &ephemeral {
+            self.delete_content(&template.sid).await?;
+        }
+        self.content_cache.clear();
+        Ok(ephemeral.len())
+    }
+
+    pub async fn fetch_content(
+        &self,
+        content_sid: &str,
+    ) -> Result<ContentTemplate, Box<dyn Error>> {
+        let url = format!("{}/Content/{}", CONTENT_API, content_sid);
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    /// Fetches the template and checks `variables` against it.
+    pub async fn validate_content_variables(
+        &self,
+        content_sid: &str,
+        variables: &ContentVariables,
+    ) -> Result<(), Box<dyn Error>> {
+        let template = self.fetch_content(content_sid).await?;
+        variables.validate_against(&template)?;
+        Ok(())
+    }
+
+    /// Submits a content template to WhatsApp for approval under `name`.
+    pub async fn submit_whatsapp_approval(
+        &self,
+        content_sid: &str,
+        name: &str,
+        category: TemplateCategory,
+    ) -> Result<WhatsAppApproval, Box<dyn Error>> {
+        validate_template_name(name)?;
+        let url = format!(
+            "{}/Content/{}/ApprovalRequests/whatsapp",
+            CONTENT_API, content_sid
+        );
+        send_json(
+            self.request(Method::POST, &url)
+                .json(&WhatsAppApprovalSubmission { name, category }),
+        )
+        .await
+    }
+
+    /// Current WhatsApp approval status of a template; `Unsubmitted` if it
+    /// was never submitted.
+    pub async fn fetch_whatsapp_approval(
+        &self,
+        content_sid: &str,
+    ) -> Result<WhatsAppApproval, Box<dyn Error>> {
+        let url = format!("{}/Content/{}/ApprovalRequests", CONTENT_API, content_sid);
+        let requests: ApprovalRequests = send_json(self.request(Method::GET, &url)).await?;
+        Ok(requests.whatsapp.unwrap_or(WhatsAppApproval {
+            name: None,
+            category: None,
+            status: ApprovalStatus::Unsubmitted,
+            rejection_reason: None,
+            content_type: None,
+            allow_category_change: false,
+        }))
+    }
+
+    /// Fails with `ContentError::NotApproved` unless WhatsApp has approved
+    /// the template.
+    pub async fn ensure_whatsapp_approved(
+        &self,
+        content_sid: &str,
+    ) -> Result<WhatsAppApproval, Box<dyn Error>> {
+        let approval = self.fetch_whatsapp_approval(content_sid).await?;
+        if !approval.is_approved() {
+            return Err(Box::new(ContentError::NotApproved {
+                content_sid: content_sid.to_string(),
+                status: approval.status,
+                rejection_reason: approval.rejection_reason,
+            }));
+        }
+        Ok(approval)
+    }
+
+    pub async fn delete_content(&self, content_sid: &str) -> Result<(), Box<dyn Error>> {
+        let url = format!("{}/Content/{}", CONTENT_API, content_sid);
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(name: &str, language: &str) -> ContentTemplate {
+        ContentTemplate {
+            sid: format!("HX_{}_{}", name, language),
+            account_sid: "AC123".to_string(),
+            friendly_name: Some(name.to_string()),
+            language: language.to_string(),
+            variables: BTreeMap::new(),
+            types: ContentTypes::default(),
+            url: None,
+            date_created: None,
+            date_updated: None,
+        }
+    }
+
+    #[test]
+    fn cache_expires_after_ttl() {
+        let cache = ContentCache::default();
+        assert!(!cache.is_fresh());
+        assert!(cache.lookup("welcome", "en").is_none());
+
+        cache.store(vec![
+            template("welcome", "en"),
+            template("welcome", "pt_BR"),
+        ]);
+        assert!(cache.is_fresh());
+        assert_eq!(cache.lookup("welcome", "pt-br").unwrap().language, "pt_BR");
+        assert_eq!(cache.lookup("welcome", "fr").unwrap().language, "en");
+        assert!(cache.lookup("goodbye", "en").is_none());
+
+        if let Some(stale) = Instant::now().checked_sub(CONTENT_CACHE_TTL) {
+            cache.templates.lock().unwrap().as_mut().unwrap().fetched_at = stale;
+            assert!(!cache.is_fresh());
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// An exact decimal amount, such as a price or a total, stored as integer
+/// `units` of `10^-scale`: `12.50` is 1250 units at scale 2.
+///
+/// Arithmetic keeps the larger scale of its operands and panics on
+/// overflow, like the integer operators.
+///
+/// # Example
+/// ```
+/// use twilio_rs::decimal::Decimal;
+///
+/// let price: Decimal = "0.0079".parse().unwrap();
+/// assert_eq!((price * 3).to_string(), "0.0237");
+/// assert_eq!(price + "1.5".parse().unwrap(), "1.5079".parse().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Decimal {
+    units: i128,
+    scale: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDecimalError(pub String);
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' is not a plain decimal number", self.0)
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+impl Decimal {
+    pub const ZERO: Decimal = Decimal { units: 0, scale: 0 };
+
+    pub fn new(units: i128, scale: u32) -> Self {
+        Self { units, scale }
+    }
+
+    pub fn units(&self) -> i128 {
+        self.units
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.units < 0
+    }
+
+    /// The same amount with `scale` fractional digits, if that does not
+    /// lose precision or overflow.
+    pub fn rescale(&self, scale: u32) -> Option<Self> {
+        let units = if scale >= self.scale {
+            self.units
+                .checked_mul(10i128.checked_pow(scale - self.scale)?)?
+        } else {
+            let divisor = 10i128.checked_pow(self.scale - scale)?;
+            if self.units % divisor != 0 {
+                return None;
+            }
+            self.units / divisor
+        };
+        Some(Self { units, scale })
+    }
+
+    fn approximate(&self) -> f64 {
+        self.units as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let units = self
+            .rescale(scale)?
+            .units
+            .checked_add(other.rescale(scale)?.units)?;
+        Some(Self { units, scale })
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            units: self.units.checked_neg()?,
+            scale: self.scale,
+        })
+    }
+
+    pub fn checked_mul(self, factor: i128) -> Option<Self> {
+        Some(Self {
+            units: self.units.checked_mul(factor)?,
+            scale: self.scale,
+        })
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || ParseDecimalError(value.to_string());
+        let trimmed = value.trim();
+        let (negative, digits) = match trimmed.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty() && fraction.is_empty()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(error());
+        }
+        let magnitude: i128 = format!("{}{}", whole, fraction)
+            .parse()
+            .map_err(|_| error())?;
+        Ok(Self {
+            units: if negative { -magnitude } else { magnitude },
+            scale: u32::try_from(fraction.len()).map_err(|_| error())?,
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", self.units.unsigned_abs(), width = scale + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - scale);
+        if self.units < 0 {
+            f.write_str("-")?;
+        }
+        f.write_str(whole)?;
+        if !fraction.is_empty() {
+            write!(f, ".{}", fraction)?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        match (self.rescale(scale), other.rescale(scale)) {
+            (Some(a), Some(b)) => a.units.cmp(&b.units),
+            // Only amounts far beyond any price overflow when rescaled.
+            _ => self.approximate().total_cmp(&other.approximate()),
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Equal amounts compare equal regardless of scale (`0.10 == 0.1`).
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: Decimal) -> Decimal {
+        self.checked_add(other).expect("decimal overflow")
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: Decimal) -> Decimal {
+        self.checked_sub(other).expect("decimal overflow")
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        self.checked_neg().expect("decimal overflow")
+    }
+}
+
+impl Mul<u32> for Decimal {
+    type Output = Decimal;
+
+    fn mul(self, factor: u32) -> Decimal {
+        self.checked_mul(factor as i128).expect("decimal overflow")
+    }
+}
+
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::ZERO, Add::add)
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Reads a decimal sent either as a JSON string or a JSON number, keeping
+/// the number's decimal text so no precision is lost to `f64`.
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        decimal_string(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Deserializes a JSON string or number as its decimal text, for fields
+/// that keep the value as sent.
+pub fn decimal_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Text {
+        String(String),
+        Number(serde_json::Number),
+    }
+    Ok(match Text::deserialize(deserializer)? {
+        Text::String(text) => text,
+        Text::Number(number) => number.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_formats_plain_decimals() {
+        for (input, output) in [
+            ("0.10", "0.10"),
+            ("-0.00750", "-0.00750"),
+            ("+3", "3"),
+            (".5", "0.5"),
+            ("12.", "12"),
+        ] {
+            assert_eq!(d(input).to_string(), output);
+        }
+        for input in ["", "-", ".", "1e3", "1,5", "NaN", "0x10"] {
+            assert!(input.parse::<Decimal>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn arithmetic_is_exact() {
+        let sum: Decimal = std::iter::repeat_n(d("0.1"), 10).sum();
+        assert_eq!(sum, d("1"));
+        assert_eq!((d("0.0079") * 3).to_string(), "0.0237");
+        assert_eq!((d("1.5") - d("2.25")).to_string(), "-0.75");
+        assert_eq!((-d("-0.0075")).to_string(), "0.0075");
+        assert!(d("0.0083") > d("0.008"));
+        assert_eq!(d("0.10"), d("0.1"));
+    }
+
+    #[test]
+    fn deserializes_strings_and_numbers() {
+        let prices: Vec<Decimal> = serde_json::from_str(r#"["0.10", 12.5, 3]"#).unwrap();
+        assert_eq!(prices, [d("0.10"), d("12.5"), d("3")]);
+        assert_eq!(serde_json::to_string(&d("0.10")).unwrap(), r#""0.10""#);
+        assert!(serde_json::from_str::<Decimal>("1e21").is_err());
+    }
+}
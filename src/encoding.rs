@@ -0,0 +1,256 @@
+use std::fmt;
+
+/// GSM 03.38 basic character set (one septet each).
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+
+/// GSM 03.38 extension table; each costs an escape septet plus itself.
+const GSM7_EXTENDED: &str = "\u{0c}^{}\\[~]|€";
+
+/// Unicode look-alikes and their GSM-7 replacements, mirroring Twilio's
+/// Smart Encoding.
+const SMART_REPLACEMENTS: &[(char, &str)] = &[
+    ('\u{00A0}', " "),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{200B}', ""),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+    ('\u{2015}', "-"),
+    ('\u{2212}', "-"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', "'"),
+    ('\u{201B}', "'"),
+    ('\u{2032}', "'"),
+    ('\u{00B4}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{201E}', "\""),
+    ('\u{201F}', "\""),
+    ('\u{2033}', "\""),
+    ('\u{00AB}', "\""),
+    ('\u{00BB}', "\""),
+    ('\u{2026}', "..."),
+    ('\u{2022}', "-"),
+    ('\u{00B7}', "-"),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{02C6}', "^"),
+    ('\u{02DC}', "~"),
+    ('\u{FF0C}', ","),
+    ('\u{FF1A}', ":"),
+    ('\u{FF1B}', ";"),
+    ('\u{FF01}', "!"),
+    ('\u{FF1F}', "?"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmsEncoding {
+    Gsm7,
+    Ucs2,
+}
+
+impl SmsEncoding {
+    /// Units available when the whole message fits in one segment.
+    pub fn single_segment_limit(&self) -> usize {
+        match self {
+            SmsEncoding::Gsm7 => 160,
+            SmsEncoding::Ucs2 => 70,
+        }
+    }
+
+    /// Units available per segment once the UDH for concatenation is added.
+    pub fn multi_segment_limit(&self) -> usize {
+        match self {
+            SmsEncoding::Gsm7 => 153,
+            SmsEncoding::Ucs2 => 67,
+        }
+    }
+}
+
+impl fmt::Display for SmsEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SmsEncoding::Gsm7 => f.write_str("GSM-7"),
+            SmsEncoding::Ucs2 => f.write_str("UCS-2"),
+        }
+    }
+}
+
+/// How a body will be split into SMS segments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentInfo {
+    pub encoding: SmsEncoding,
+    pub segments: usize,
+    /// Septets (GSM-7) or UTF-16 code units (UCS-2) used by the whole body.
+    pub units: usize,
+    /// Units available in each segment for this body.
+    pub units_per_segment: usize,
+    /// Units used in each segment, in order.
+    pub segment_usage: Vec<usize>,
+    /// Characters that forced UCS-2, in order of appearance, deduplicated.
+    pub non_gsm_characters: Vec<char>,
+}
+
+impl SegmentInfo {
+    /// Units left before another segment is needed.
+    pub fn remaining_in_last_segment(&self) -> usize {
+        self.segment_usage
+            .last()
+            .map(|used| self.units_per_segment - used)
+            .unwrap_or(self.units_per_segment)
+    }
+}
+
+pub fn is_gsm7_char(c: char) -> bool {
+    GSM7_BASIC.contains(c) || GSM7_EXTENDED.contains(c)
+}
+
+fn unit_cost(c: char, encoding: SmsEncoding) -> usize {
+    match encoding {
+        SmsEncoding::Gsm7 if GSM7_EXTENDED.contains(c) => 2,
+        SmsEncoding::Gsm7 => 1,
+        SmsEncoding::Ucs2 => c.len_utf16(),
+    }
+}
+
+/// Reports the encoding Twilio will use for `body` and how many segments it
+/// will be billed as.
+pub fn analyze_sms(body: &str) -> SegmentInfo {
+    let mut non_gsm_characters = Vec::new();
+    for c in body.chars().filter(|c| !is_gsm7_char(*c)) {
+        if !non_gsm_characters.contains(&c) {
+            non_gsm_characters.push(c);
+        }
+    }
+
+    let encoding = if non_gsm_characters.is_empty() {
+        SmsEncoding::Gsm7
+    } else {
+        SmsEncoding::Ucs2
+    };
+
+    let units: usize = body.chars().map(|c| unit_cost(c, encoding)).sum();
+
+    if units <= encoding.single_segment_limit() {
+        return SegmentInfo {
+            encoding,
+            segments: 1,
+            units,
+            units_per_segment: encoding.single_segment_limit(),
+            segment_usage: vec![units],
+            non_gsm_characters,
+        };
+    }
+
+    // Escape sequences and surrogate pairs are never split across segments,
+    // so pack character by character rather than dividing.
+    let limit = encoding.multi_segment_limit();
+    let mut segment_usage = vec![0];
+    for c in body.chars() {
+        let cost = unit_cost(c, encoding);
+        let current = segment_usage.last_mut().expect("at least one segment");
+        if *current + cost > limit {
+            segment_usage.push(cost);
+        } else {
+            *current += cost;
+        }
+    }
+
+    SegmentInfo {
+        encoding,
+        segments: segment_usage.len(),
+        units,
+        units_per_segment: limit,
+        segment_usage,
+        non_gsm_characters,
+    }
+}
+
+/// Replaces Unicode look-alikes (smart quotes, dashes, ellipses, odd spaces)
+/// with GSM-7 equivalents so the body can stay in GSM-7.
+pub fn smart_encode(body: &str) -> String {
+    let mut encoded = String::with_capacity(body.len());
+    for c in body.chars() {
+        match SMART_REPLACEMENTS.iter().find(|(from, _)| *from == c) {
+            Some((_, to)) => encoded.push_str(to),
+            None => encoded.push(c),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_characters_cost_two_septets() {
+        let info = analyze_sms("€[]");
+        assert_eq!(info.encoding, SmsEncoding::Gsm7);
+        assert_eq!(info.units, 6);
+        assert!(info.non_gsm_characters.is_empty());
+    }
+
+    #[test]
+    fn gsm7_single_segment_boundary() {
+        let info = analyze_sms(&"a".repeat(160));
+        assert_eq!((info.segments, info.units_per_segment), (1, 160));
+        assert_eq!(info.remaining_in_last_segment(), 0);
+
+        let info = analyze_sms(&"a".repeat(161));
+        assert_eq!(info.segments, 2);
+        assert_eq!(info.segment_usage, vec![153, 8]);
+    }
+
+    #[test]
+    fn gsm7_multi_segment_boundary() {
+        assert_eq!(analyze_sms(&"a".repeat(306)).segments, 2);
+        assert_eq!(analyze_sms(&"a".repeat(307)).segments, 3);
+    }
+
+    #[test]
+    fn extension_escape_is_not_split_across_segments() {
+        // 152 septets, then a 2-septet `€` that no longer fits in segment one.
+        let body = format!("{}€{}", "a".repeat(152), "a".repeat(10));
+        let info = analyze_sms(&body);
+        assert_eq!(info.units, 164);
+        assert_eq!(info.segment_usage, vec![152, 12]);
+    }
+
+    #[test]
+    fn ucs2_boundaries() {
+        let info = analyze_sms(&"й".repeat(70));
+        assert_eq!(info.encoding, SmsEncoding::Ucs2);
+        assert_eq!((info.segments, info.units_per_segment), (1, 70));
+
+        let info = analyze_sms(&"й".repeat(71));
+        assert_eq!(info.segment_usage, vec![67, 4]);
+        assert_eq!(info.non_gsm_characters, vec!['й']);
+    }
+
+    #[test]
+    fn surrogate_pairs_are_not_split_across_segments() {
+        // 66 code units, then a 2-unit emoji that would straddle the boundary.
+        let body = format!("{}😀{}", "й".repeat(66), "й".repeat(5));
+        let info = analyze_sms(&body);
+        assert_eq!(info.units, 73);
+        assert_eq!(info.segment_usage, vec![66, 7]);
+    }
+
+    #[test]
+    fn smart_encoding_keeps_messages_in_gsm7() {
+        let encoded = smart_encode("“Hi” — it’s done…");
+        assert_eq!(encoded, "\"Hi\" - it's done...");
+        assert_eq!(analyze_sms(&encoded).encoding, SmsEncoding::Gsm7);
+        assert_eq!(smart_encode("zero\u{200B}width"), "zerowidth");
+    }
+}
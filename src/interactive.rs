@@ -0,0 +1,776 @@
+// interactive.rs
+
+use crate::whatsapp::{
+    InteractiveButton, InteractiveHeader, InteractiveListSection, ProductSection,
+};
+use std::error::Error;
+use std::fmt;
+
+pub const MAX_BUTTONS: usize = 3;
+pub const MAX_BUTTON_TITLE: usize = 20;
+pub const MAX_BUTTON_ID: usize = 256;
+pub const MAX_URL_BUTTONS: usize = 2;
+pub const MAX_PHONE_BUTTONS: usize = 1;
+pub const MAX_COPY_CODE_BUTTONS: usize = 1;
+pub const MAX_BUTTON_URL: usize = 2000;
+pub const MAX_BUTTON_PHONE: usize = 20;
+pub const MAX_COPY_CODE: usize = 15;
+pub const MAX_LIST_SECTIONS: usize = 10;
+pub const MAX_LIST_ROWS: usize = 10;
+pub const MAX_LIST_BUTTON: usize = 20;
+pub const MAX_SECTION_TITLE: usize = 24;
+pub const MAX_ROW_TITLE: usize = 24;
+pub const MAX_ROW_ID: usize = 200;
+pub const MAX_ROW_DESCRIPTION: usize = 72;
+pub const MAX_HEADER_TEXT: usize = 60;
+pub const MAX_BODY_TEXT: usize = 1024;
+pub const MAX_FOOTER_TEXT: usize = 60;
+pub const MAX_PRODUCTS: usize = 30;
+pub const MAX_PRODUCT_ID: usize = 100;
+
+/// The part of an interactive message a validation error refers to.
+/// Buttons, sections and rows are numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractiveField {
+    Header,
+    HeaderMedia,
+    Body,
+    Footer,
+    ButtonTitle(usize),
+    ButtonId(usize),
+    ButtonUrl(usize),
+    ButtonPhone(usize),
+    ButtonCode(usize),
+    ListButton,
+    SectionTitle(usize),
+    RowTitle { section: usize, row: usize },
+    RowId { section: usize, row: usize },
+    RowDescription { section: usize, row: usize },
+    CatalogId,
+    ProductId { section: usize, product: usize },
+}
+
+impl fmt::Display for InteractiveField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InteractiveField::Header => write!(f, "header text"),
+            InteractiveField::HeaderMedia => write!(f, "header media URL"),
+            InteractiveField::Body => write!(f, "body text"),
+            InteractiveField::Footer => write!(f, "footer text"),
+            InteractiveField::ButtonTitle(button) => write!(f, "title of button {}", button),
+            InteractiveField::ButtonId(button) => write!(f, "id of button {}", button),
+            InteractiveField::ButtonUrl(button) => write!(f, "URL of button {}", button),
+            InteractiveField::ButtonPhone(button) => write!(f, "phone number of button {}", button),
+            InteractiveField::ButtonCode(button) => write!(f, "code of button {}", button),
+            InteractiveField::ListButton => write!(f, "list button text"),
+            InteractiveField::SectionTitle(section) => write!(f, "title of section {}", section),
+            InteractiveField::RowTitle { section, row } => {
+                write!(f, "title of row {} in section {}", row, section)
+            }
+            InteractiveField::RowId { section, row } => {
+                write!(f, "id of row {} in section {}", row, section)
+            }
+            InteractiveField::RowDescription { section, row } => {
+                write!(f, "description of row {} in section {}", row, section)
+            }
+            InteractiveField::CatalogId => write!(f, "catalog id"),
+            InteractiveField::ProductId { section, product } => {
+                write!(f, "id of product {} in section {}", product, section)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InteractiveError {
+    Empty(InteractiveField),
+    TooLong {
+        field: InteractiveField,
+        length: usize,
+        max: usize,
+    },
+    /// Also returned when there are no buttons.
+    TooManyButtons {
+        count: usize,
+        max: usize,
+    },
+    /// WhatsApp limits how many link, phone and copy-code buttons one
+    /// message may carry.
+    TooManyButtonsOfKind {
+        kind: &'static str,
+        count: usize,
+        max: usize,
+    },
+    /// Media and link URLs must be absolute `http(s)` URLs.
+    InvalidUrl {
+        field: InteractiveField,
+        url: String,
+    },
+    TooManySections {
+        count: usize,
+        max: usize,
+    },
+    /// Rows are limited across all sections combined; also returned when
+    /// there are no rows.
+    TooManyRows {
+        count: usize,
+        max: usize,
+    },
+    /// Products are limited across all sections combined; also returned
+    /// when there are none.
+    TooManyProducts {
+        count: usize,
+        max: usize,
+    },
+    /// Button and row IDs must be unique within a message.
+    DuplicateId(String),
+    /// Every section needs a title once there is more than one.
+    MissingSectionTitle(usize),
+}
+
+impl fmt::Display for InteractiveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InteractiveError::Empty(field) => write!(f, "The {} must not be empty", field),
+            InteractiveError::TooLong { field, length, max } => write!(
+                f,
+                "The {} is {} characters long; WhatsApp allows at most {}",
+                field, length, max
+            ),
+            InteractiveError::TooManyButtons { count, max } => write!(
+                f,
+                "WhatsApp interactive messages need between 1 and {} buttons, got {}",
+                max, count
+            ),
+            InteractiveError::TooManyButtonsOfKind { kind, count, max } => write!(
+                f,
+                "WhatsApp allows at most {} {} button(s) per message, got {}",
+                max, kind, count
+            ),
+            InteractiveError::InvalidUrl { field, url } => {
+                write!(f, "The {} '{}' is not an http(s) URL", field, url)
+            }
+            InteractiveError::TooManySections { count, max } => write!(
+                f,
+                "WhatsApp list messages support maximum {} sections, got {}",
+                max, count
+            ),
+            InteractiveError::TooManyRows { count, max } => write!(
+                f,
+                "WhatsApp list messages need between 1 and {} rows in total, got {}",
+                max, count
+            ),
+            InteractiveError::TooManyProducts { count, max } => write!(
+                f,
+                "WhatsApp product messages need between 1 and {} products in total, got {}",
+                max, count
+            ),
+            InteractiveError::DuplicateId(id) => {
+                write!(f, "The id '{}' is used more than once", id)
+            }
+            InteractiveError::MissingSectionTitle(section) => write!(
+                f,
+                "Section {} needs a title because the list has several sections",
+                section
+            ),
+        }
+    }
+}
+
+impl Error for InteractiveError {}
+
+fn check_length(field: InteractiveField, text: &str, max: usize) -> Result<(), InteractiveError> {
+    let length = text.chars().count();
+    if length > max {
+        return Err(InteractiveError::TooLong { field, length, max });
+    }
+    Ok(())
+}
+
+fn check_required(field: InteractiveField, text: &str, max: usize) -> Result<(), InteractiveError> {
+    if text.trim().is_empty() {
+        return Err(InteractiveError::Empty(field));
+    }
+    check_length(field, text, max)
+}
+
+fn check_url(field: InteractiveField, url: &str) -> Result<(), InteractiveError> {
+    check_required(field, url, MAX_BUTTON_URL)?;
+    if !url.starts_with("https://") && !url.starts_with("http://") {
+        return Err(InteractiveError::InvalidUrl {
+            field,
+            url: url.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn check_unique<'a>(seen: &mut Vec<&'a str>, id: &'a str) -> Result<(), InteractiveError> {
+    if seen.contains(&id) {
+        return Err(InteractiveError::DuplicateId(id.to_string()));
+    }
+    seen.push(id);
+    Ok(())
+}
+
+/// Checks the header, body and footer shared by every interactive type.
+pub fn validate_text(
+    header: Option<&str>,
+    body: &str,
+    footer: Option<&str>,
+) -> Result<(), InteractiveError> {
+    if let Some(header) = header {
+        check_length(InteractiveField::Header, header, MAX_HEADER_TEXT)?;
+    }
+    check_required(InteractiveField::Body, body, MAX_BODY_TEXT)?;
+    if let Some(footer) = footer {
+        check_length(InteractiveField::Footer, footer, MAX_FOOTER_TEXT)?;
+    }
+    Ok(())
+}
+
+/// Checks a button message against WhatsApp's limits.
+pub fn validate_buttons(
+    header: &InteractiveHeader,
+    body: &str,
+    footer: Option<&str>,
+    buttons: &[InteractiveButton],
+) -> Result<(), InteractiveError> {
+    validate_text(header.text(), body, footer)?;
+    if let Some(url) = header.media_url() {
+        check_url(InteractiveField::HeaderMedia, url)?;
+    }
+
+    if buttons.is_empty() || buttons.len() > MAX_BUTTONS {
+        return Err(InteractiveError::TooManyButtons {
+            count: buttons.len(),
+            max: MAX_BUTTONS,
+        });
+    }
+
+    let mut ids = Vec::new();
+    let (mut urls, mut phones, mut codes) = (0, 0, 0);
+    for (index, button) in buttons.iter().enumerate() {
+        let number = index + 1;
+        check_required(
+            InteractiveField::ButtonTitle(number),
+            button.title(),
+            MAX_BUTTON_TITLE,
+        )?;
+        match button {
+            InteractiveButton::Reply { id, .. } => {
+                check_required(InteractiveField::ButtonId(number), id, MAX_BUTTON_ID)?;
+                check_unique(&mut ids, id)?;
+            }
+            InteractiveButton::Url { url, .. } => {
+                check_url(InteractiveField::ButtonUrl(number), url)?;
+                urls += 1;
+            }
+            InteractiveButton::PhoneNumber { phone, .. } => {
+                check_required(
+                    InteractiveField::ButtonPhone(number),
+                    phone,
+                    MAX_BUTTON_PHONE,
+                )?;
+                phones += 1;
+            }
+            InteractiveButton::CopyCode { code, .. } => {
+                check_required(InteractiveField::ButtonCode(number), code, MAX_COPY_CODE)?;
+                codes += 1;
+            }
+        }
+    }
+
+    for (kind, count, max) in [
+        ("URL", urls, MAX_URL_BUTTONS),
+        ("phone number", phones, MAX_PHONE_BUTTONS),
+        ("copy code", codes, MAX_COPY_CODE_BUTTONS),
+    ] {
+        if count > max {
+            return Err(InteractiveError::TooManyButtonsOfKind { kind, count, max });
+        }
+    }
+    Ok(())
+}
+
+/// Checks a list message against WhatsApp's limits.
+pub fn validate_list(
+    header: Option<&str>,
+    body: &str,
+    footer: Option<&str>,
+    button_text: &str,
+    sections: &[InteractiveListSection],
+) -> Result<(), InteractiveError> {
+    validate_text(header, body, footer)?;
+    check_required(InteractiveField::ListButton, button_text, MAX_LIST_BUTTON)?;
+
+    if sections.len() > MAX_LIST_SECTIONS {
+        return Err(InteractiveError::TooManySections {
+            count: sections.len(),
+            max: MAX_LIST_SECTIONS,
+        });
+    }
+    let rows: usize = sections.iter().map(|section| section.rows.len()).sum();
+    if rows == 0 || rows > MAX_LIST_ROWS {
+        return Err(InteractiveError::TooManyRows {
+            count: rows,
+            max: MAX_LIST_ROWS,
+        });
+    }
+
+    let mut ids = Vec::new();
+    for (section_index, section) in sections.iter().enumerate() {
+        let section_number = section_index + 1;
+        match &section.title {
+            Some(title) => check_required(
+                InteractiveField::SectionTitle(section_number),
+                title,
+                MAX_SECTION_TITLE,
+            )?,
+            None if sections.len() > 1 => {
+                return Err(InteractiveError::MissingSectionTitle(section_number))
+            }
+            None => {}
+        }
+
+        for (row_index, row) in section.rows.iter().enumerate() {
+            let (section, row_number) = (section_number, row_index + 1);
+            check_required(
+                InteractiveField::RowTitle {
+                    section,
+                    row: row_number,
+                },
+                &row.title,
+                MAX_ROW_TITLE,
+            )?;
+            check_required(
+                InteractiveField::RowId {
+                    section,
+                    row: row_number,
+                },
+                &row.id,
+                MAX_ROW_ID,
+            )?;
+            if let Some(description) = &row.description {
+                check_length(
+                    InteractiveField::RowDescription {
+                        section,
+                        row: row_number,
+                    },
+                    description,
+                    MAX_ROW_DESCRIPTION,
+                )?;
+            }
+            check_unique(&mut ids, &row.id)?;
+        }
+    }
+    Ok(())
+}
+
+/// Checks a catalog or single-product message against WhatsApp's limits.
+pub fn validate_catalog(
+    catalog_id: &str,
+    body: &str,
+    footer: Option<&str>,
+    product_id: Option<&str>,
+) -> Result<(), InteractiveError> {
+    check_required(InteractiveField::CatalogId, catalog_id, MAX_PRODUCT_ID)?;
+    validate_text(None, body, footer)?;
+    if let Some(product_id) = product_id {
+        check_required(
+            InteractiveField::ProductId {
+                section: 1,
+                product: 1,
+            },
+            product_id,
+            MAX_PRODUCT_ID,
+        )?;
+    }
+    Ok(())
+}
+
+/// Checks a multi-product message against WhatsApp's limits. Unlike lists,
+/// product messages always need a header and titled sections.
+pub fn validate_product_list(
+    catalog_id: &str,
+    header: &str,
+    body: &str,
+    footer: Option<&str>,
+    sections: &[ProductSection],
+) -> Result<(), InteractiveError> {
+    check_required(InteractiveField::CatalogId, catalog_id, MAX_PRODUCT_ID)?;
+    check_required(InteractiveField::Header, header, MAX_HEADER_TEXT)?;
+    validate_text(None, body, footer)?;
+
+    if sections.len() > MAX_LIST_SECTIONS {
+        return Err(InteractiveError::TooManySections {
+            count: sections.len(),
+            max: MAX_LIST_SECTIONS,
+        });
+    }
+    let products: usize = sections
+        .iter()
+        .map(|section| section.product_ids.len())
+        .sum();
+    if products == 0 || products > MAX_PRODUCTS {
+        return Err(InteractiveError::TooManyProducts {
+            count: products,
+            max: MAX_PRODUCTS,
+        });
+    }
+
+    let mut ids = Vec::new();
+    for (section_index, section) in sections.iter().enumerate() {
+        let section_number = section_index + 1;
+        check_required(
+            InteractiveField::SectionTitle(section_number),
+            &section.title,
+            MAX_SECTION_TITLE,
+        )?;
+        for (product_index, product_id) in section.product_ids.iter().enumerate() {
+            check_required(
+                InteractiveField::ProductId {
+                    section: section_number,
+                    product: product_index + 1,
+                },
+                product_id,
+                MAX_PRODUCT_ID,
+            )?;
+            check_unique(&mut ids, product_id)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::whatsapp::InteractiveListRow;
+
+    fn row(id: &str) -> InteractiveListRow {
+        InteractiveListRow {
+            id: id.to_string(),
+            title: "Row".to_string(),
+            description: None,
+        }
+    }
+
+    fn section(title: Option<&str>, rows: Vec<InteractiveListRow>) -> InteractiveListSection {
+        InteractiveListSection {
+            title: title.map(str::to_string),
+            rows,
+        }
+    }
+
+    fn products(title: &str, ids: &[&str]) -> ProductSection {
+        ProductSection {
+            title: title.to_string(),
+            product_ids: ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    fn buttons(buttons: &[InteractiveButton]) -> Result<(), InteractiveError> {
+        validate_buttons(&"Header".into(), "Body", None, buttons)
+    }
+
+    fn list(sections: &[InteractiveListSection]) -> Result<(), InteractiveError> {
+        validate_list(None, "Body", None, "Choose", sections)
+    }
+
+    fn list_row(row: InteractiveListRow) -> Result<(), InteractiveError> {
+        list(&[section(None, vec![row])])
+    }
+
+    type Check = fn(&str) -> Result<(), InteractiveError>;
+
+    #[test]
+    fn text_limits_count_characters() {
+        let cases: Vec<(InteractiveField, usize, &str, Check)> = vec![
+            (InteractiveField::Header, MAX_HEADER_TEXT, "", |t| {
+                validate_text(Some(t), "Body", None)
+            }),
+            (InteractiveField::Body, MAX_BODY_TEXT, "", |t| {
+                validate_text(None, t, None)
+            }),
+            (InteractiveField::Footer, MAX_FOOTER_TEXT, "", |t| {
+                validate_text(None, "Body", Some(t))
+            }),
+            (
+                InteractiveField::HeaderMedia,
+                MAX_BUTTON_URL,
+                "https://",
+                |t| {
+                    validate_buttons(
+                        &InteractiveHeader::Image(t.to_string()),
+                        "Body",
+                        None,
+                        &[InteractiveButton::reply("id", "Ok")],
+                    )
+                },
+            ),
+            (
+                InteractiveField::ButtonTitle(1),
+                MAX_BUTTON_TITLE,
+                "",
+                |t| buttons(&[InteractiveButton::reply("id", t)]),
+            ),
+            (InteractiveField::ButtonId(1), MAX_BUTTON_ID, "", |t| {
+                buttons(&[InteractiveButton::reply(t, "Ok")])
+            }),
+            (
+                InteractiveField::ButtonUrl(1),
+                MAX_BUTTON_URL,
+                "https://",
+                |t| buttons(&[InteractiveButton::url("Open", t)]),
+            ),
+            (
+                InteractiveField::ButtonPhone(1),
+                MAX_BUTTON_PHONE,
+                "",
+                |t| buttons(&[InteractiveButton::phone_number("Call", t)]),
+            ),
+            (InteractiveField::ButtonCode(1), MAX_COPY_CODE, "", |t| {
+                buttons(&[InteractiveButton::copy_code("Copy", t)])
+            }),
+            (InteractiveField::ListButton, MAX_LIST_BUTTON, "", |t| {
+                validate_list(None, "Body", None, t, &[section(None, vec![row("1")])])
+            }),
+            (
+                InteractiveField::SectionTitle(1),
+                MAX_SECTION_TITLE,
+                "",
+                |t| list(&[section(Some(t), vec![row("1")])]),
+            ),
+            (
+                InteractiveField::RowTitle { section: 1, row: 1 },
+                MAX_ROW_TITLE,
+                "",
+                |t| {
+                    list_row(InteractiveListRow {
+                        title: t.to_string(),
+                        ..row("1")
+                    })
+                },
+            ),
+            (
+                InteractiveField::RowId { section: 1, row: 1 },
+                MAX_ROW_ID,
+                "",
+                |t| list_row(row(t)),
+            ),
+            (
+                InteractiveField::RowDescription { section: 1, row: 1 },
+                MAX_ROW_DESCRIPTION,
+                "",
+                |t| {
+                    list_row(InteractiveListRow {
+                        description: Some(t.to_string()),
+                        ..row("1")
+                    })
+                },
+            ),
+            (InteractiveField::CatalogId, MAX_PRODUCT_ID, "", |t| {
+                validate_catalog(t, "Body", None, None)
+            }),
+            (
+                InteractiveField::ProductId {
+                    section: 1,
+                    product: 1,
+                },
+                MAX_PRODUCT_ID,
+                "",
+                |t| validate_catalog("catalog", "Body", None, Some(t)),
+            ),
+            (InteractiveField::Header, MAX_HEADER_TEXT, "", |t| {
+                validate_product_list("catalog", t, "Body", None, &[products("A", &["p1"])])
+            }),
+            (
+                InteractiveField::SectionTitle(1),
+                MAX_SECTION_TITLE,
+                "",
+                |t| {
+                    validate_product_list(
+                        "catalog",
+                        "Header",
+                        "Body",
+                        None,
+                        &[products(t, &["p1"])],
+                    )
+                },
+            ),
+            (InteractiveField::Body, MAX_BODY_TEXT, "", |t| {
+                validate_catalog("catalog", t, None, None)
+            }),
+        ];
+
+        for (field, max, prefix, check) in cases {
+            // Emoji are 4 bytes each, so byte counting would reject `at_max`.
+            let text = |length: usize| format!("{}{}", prefix, "😀".repeat(length - prefix.len()));
+            assert_eq!(check(&text(max)), Ok(()), "{} at {}", field, max);
+            assert_eq!(
+                check(&text(max + 1)),
+                Err(InteractiveError::TooLong {
+                    field,
+                    length: max + 1,
+                    max
+                }),
+                "{} at {}",
+                field,
+                max + 1
+            );
+        }
+    }
+
+    #[test]
+    fn button_counts() {
+        let reply = |n: usize| InteractiveButton::reply(&n.to_string(), "Ok");
+        let replies = |count: usize| (1..=count).map(reply).collect::<Vec<_>>();
+        assert_eq!(buttons(&replies(MAX_BUTTONS)), Ok(()));
+        for count in [0, MAX_BUTTONS + 1] {
+            assert_eq!(
+                buttons(&replies(count)),
+                Err(InteractiveError::TooManyButtons {
+                    count,
+                    max: MAX_BUTTONS
+                })
+            );
+        }
+
+        let url = || InteractiveButton::url("Open", "https://example.com");
+        let phone = || InteractiveButton::phone_number("Call", "+14155550100");
+        let code = || InteractiveButton::copy_code("Copy", "SAVE10");
+        assert_eq!(buttons(&[url(), url(), phone()]), Ok(()));
+        for (kind, set, max) in [
+            ("URL", vec![url(), url(), url()], MAX_URL_BUTTONS),
+            ("phone number", vec![phone(), phone()], MAX_PHONE_BUTTONS),
+            ("copy code", vec![code(), code()], MAX_COPY_CODE_BUTTONS),
+        ] {
+            assert_eq!(
+                buttons(&set),
+                Err(InteractiveError::TooManyButtonsOfKind {
+                    kind,
+                    count: set.len(),
+                    max
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn duplicate_ids_are_rejected() {
+        assert_eq!(
+            buttons(&[
+                InteractiveButton::reply("yes", "Yes"),
+                InteractiveButton::reply("yes", "Sure"),
+            ]),
+            Err(InteractiveError::DuplicateId("yes".to_string()))
+        );
+        assert_eq!(
+            list(&[
+                section(Some("A"), vec![row("1"), row("2")]),
+                section(Some("B"), vec![row("2")]),
+            ]),
+            Err(InteractiveError::DuplicateId("2".to_string()))
+        );
+        assert_eq!(
+            validate_product_list(
+                "catalog",
+                "Header",
+                "Body",
+                None,
+                &[products("A", &["p1"]), products("B", &["p1"])],
+            ),
+            Err(InteractiveError::DuplicateId("p1".to_string()))
+        );
+    }
+
+    #[test]
+    fn section_titles_are_required_once_there_are_several() {
+        assert_eq!(list(&[section(None, vec![row("1")])]), Ok(()));
+        assert_eq!(
+            list(&[
+                section(Some("A"), vec![row("1")]),
+                section(None, vec![row("2")]),
+                section(None, vec![row("3")]),
+            ]),
+            Err(InteractiveError::MissingSectionTitle(2))
+        );
+        assert_eq!(
+            list(&[section(Some(" "), vec![row("1")])]),
+            Err(InteractiveError::Empty(InteractiveField::SectionTitle(1)))
+        );
+    }
+
+    #[test]
+    fn row_and_product_counts() {
+        let rows = |count: usize| (1..=count).map(|n| row(&n.to_string())).collect();
+        assert_eq!(list(&[section(None, rows(MAX_LIST_ROWS))]), Ok(()));
+        for count in [0, MAX_LIST_ROWS + 1] {
+            assert_eq!(
+                list(&[section(None, rows(count))]),
+                Err(InteractiveError::TooManyRows {
+                    count,
+                    max: MAX_LIST_ROWS
+                })
+            );
+        }
+        let sections: Vec<_> = (0..=MAX_LIST_SECTIONS)
+            .map(|n| section(Some("S"), vec![row(&n.to_string())]))
+            .collect();
+        assert_eq!(
+            list(&sections),
+            Err(InteractiveError::TooManySections {
+                count: MAX_LIST_SECTIONS + 1,
+                max: MAX_LIST_SECTIONS
+            })
+        );
+
+        let ids: Vec<String> = (0..=MAX_PRODUCTS).map(|n| format!("p{}", n)).collect();
+        let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let product_list = |ids: &[&str]| {
+            validate_product_list("catalog", "Header", "Body", None, &[products("A", ids)])
+        };
+        assert_eq!(product_list(&ids[..MAX_PRODUCTS]), Ok(()));
+        for count in [0, MAX_PRODUCTS + 1] {
+            assert_eq!(
+                product_list(&ids[..count]),
+                Err(InteractiveError::TooManyProducts {
+                    count,
+                    max: MAX_PRODUCTS
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn empty_rows_and_products_are_rejected() {
+        assert_eq!(
+            list_row(InteractiveListRow {
+                title: "  ".to_string(),
+                ..row("1")
+            }),
+            Err(InteractiveError::Empty(InteractiveField::RowTitle {
+                section: 1,
+                row: 1
+            }))
+        );
+        assert_eq!(
+            list_row(row("")),
+            Err(InteractiveError::Empty(InteractiveField::RowId {
+                section: 1,
+                row: 1
+            }))
+        );
+        assert_eq!(
+            validate_product_list("catalog", "Header", "Body", None, &[products("A", &[""])]),
+            Err(InteractiveError::Empty(InteractiveField::ProductId {
+                section: 1,
+                product: 1
+            }))
+        );
+        assert_eq!(
+            validate_text(None, " ", None),
+            Err(InteractiveError::Empty(InteractiveField::Body))
+        );
+    }
+}
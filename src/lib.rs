@@ -1,16 +1,39 @@
 // lib.rs
 
+pub mod access_token;
+pub mod api_keys;
 pub mod client;
+pub mod conference;
+pub mod content;
+pub mod decimal;
+pub mod encoding;
+pub mod interactive;
+pub mod media_stream;
+pub mod messaging;
 pub mod payments;
+pub mod phone;
+pub mod phone_numbers;
+pub mod pricing;
+pub mod queues;
+pub mod recordings;
 pub mod signature;
 pub mod sms;
+pub mod twiml;
+pub mod twiml_parser;
+pub mod voice;
+pub mod voice_webhook;
 pub mod webhook;
 pub mod whatsapp;
+pub mod whatsapp_session;
 use actix_web::Scope;
 use std::sync::Arc;
 
 /// Re-export essential types and functions
-pub use webhook::{build_whatsapp_webhook_scope, IncomingWhatsAppMessage};
+pub use voice_webhook::build_voice_webhook_scope;
+pub use webhook::{
+    build_whatsapp_webhook_scope, build_whatsapp_webhook_scope_with_sessions,
+    IncomingWhatsAppMessage,
+};
 
 /// Type alias for auto reply handler for convenience
 pub type AutoReplyHandler = Arc<dyn Fn(String, String) -> String + Send + Sync>;
@@ -19,7 +42,7 @@ pub type AutoReplyHandler = Arc<dyn Fn(String, String) -> String + Send + Sync>;
 ///
 /// # Example
 /// ```
-/// use my_twilio_lib::{build_twilio_webhook, AutoReplyHandler};
+/// use twilio_rs::{build_twilio_webhook, AutoReplyHandler};
 /// use actix_web::{App, HttpServer};
 /// use std::sync::Arc;
 ///
@@ -0,0 +1,341 @@
+// media_stream.rs
+
+use crate::webhook::is_signed_by_twilio;
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use actix_ws::{Message, Session};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaFormat {
+    pub encoding: String,
+    pub sample_rate: u32,
+    pub channels: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamStart {
+    pub account_sid: String,
+    pub stream_sid: String,
+    pub call_sid: String,
+    #[serde(default)]
+    pub tracks: Vec<String>,
+    /// `<Parameter>` values from the `<Stream>` TwiML.
+    #[serde(default)]
+    pub custom_parameters: HashMap<String, String>,
+    pub media_format: MediaFormat,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MediaPayload {
+    /// `inbound` or `outbound`.
+    pub track: String,
+    pub chunk: String,
+    /// Milliseconds since the stream started.
+    pub timestamp: String,
+    /// Base64 encoded 8 kHz μ-law audio.
+    pub payload: String,
+}
+
+impl MediaPayload {
+    /// Decodes the payload into 16-bit linear PCM samples.
+    pub fn decode_pcm(&self) -> Result<AudioFrame, base64::DecodeError> {
+        let mulaw = STANDARD.decode(&self.payload)?;
+        Ok(AudioFrame {
+            track: self.track.clone(),
+            chunk: self.chunk.parse().unwrap_or_default(),
+            timestamp_ms: self.timestamp.parse().unwrap_or_default(),
+            samples: mulaw.into_iter().map(mulaw_to_linear).collect(),
+        })
+    }
+}
+
+/// A chunk of decoded call audio (8 kHz mono).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioFrame {
+    pub track: String,
+    pub chunk: u64,
+    pub timestamp_ms: u64,
+    pub samples: Vec<i16>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MarkPayload {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamStop {
+    pub account_sid: String,
+    pub call_sid: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DtmfPayload {
+    pub track: String,
+    pub digit: String,
+}
+
+/// Messages Twilio sends over a `<Stream>` WebSocket.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum MediaStreamEvent {
+    Connected {
+        protocol: String,
+        version: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Start {
+        sequence_number: String,
+        stream_sid: String,
+        start: StreamStart,
+    },
+    #[serde(rename_all = "camelCase")]
+    Media {
+        sequence_number: String,
+        stream_sid: String,
+        media: MediaPayload,
+    },
+    /// A mark sent with `MediaStreamSender::mark` has finished playing.
+    #[serde(rename_all = "camelCase")]
+    Mark {
+        sequence_number: String,
+        stream_sid: String,
+        mark: MarkPayload,
+    },
+    #[serde(rename_all = "camelCase")]
+    Dtmf {
+        sequence_number: String,
+        stream_sid: String,
+        dtmf: DtmfPayload,
+    },
+    #[serde(rename_all = "camelCase")]
+    Stop {
+        sequence_number: String,
+        stream_sid: String,
+        stop: StreamStop,
+    },
+    /// A text frame that is not a known event. Never sent by Twilio as such;
+    /// it carries the raw frame and the parse error to the handler.
+    #[serde(skip)]
+    Unrecognised {
+        message: String,
+        error: String,
+    },
+}
+
+impl MediaStreamEvent {
+    pub fn stream_sid(&self) -> Option<&str> {
+        match self {
+            MediaStreamEvent::Connected { .. } | MediaStreamEvent::Unrecognised { .. } => None,
+            MediaStreamEvent::Start { stream_sid, .. }
+            | MediaStreamEvent::Media { stream_sid, .. }
+            | MediaStreamEvent::Mark { stream_sid, .. }
+            | MediaStreamEvent::Dtmf { stream_sid, .. }
+            | MediaStreamEvent::Stop { stream_sid, .. } => Some(stream_sid),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct OutboundMedia<'a> {
+    payload: &'a str,
+}
+
+/// Messages sent back to Twilio on a bidirectional (`<Connect><Stream>`) stream.
+#[derive(Serialize, Debug)]
+#[serde(tag = "event", rename_all = "lowercase")]
+enum OutboundEvent<'a> {
+    #[serde(rename_all = "camelCase")]
+    Media {
+        stream_sid: &'a str,
+        media: OutboundMedia<'a>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Mark {
+        stream_sid: &'a str,
+        mark: MarkPayload,
+    },
+    #[serde(rename_all = "camelCase")]
+    Clear { stream_sid: &'a str },
+}
+
+/// Sends audio and control messages back to Twilio on one stream.
+#[derive(Clone)]
+pub struct MediaStreamSender {
+    session: Session,
+    stream_sid: String,
+}
+
+impl MediaStreamSender {
+    pub fn stream_sid(&self) -> &str {
+        &self.stream_sid
+    }
+
+    async fn send(&mut self, event: &OutboundEvent<'_>) -> Result<(), actix_ws::Closed> {
+        let json = serde_json::to_string(event).expect("outbound events always serialize");
+        self.session.text(json).await
+    }
+
+    /// Plays 8 kHz μ-law audio to the caller.
+    pub async fn send_mulaw(&mut self, mulaw: &[u8]) -> Result<(), actix_ws::Closed> {
+        let payload = STANDARD.encode(mulaw);
+        let stream_sid = self.stream_sid.clone();
+        self.send(&OutboundEvent::Media {
+            stream_sid: &stream_sid,
+            media: OutboundMedia { payload: &payload },
+        })
+        .await
+    }
+
+    /// Plays 8 kHz 16-bit linear PCM audio to the caller.
+    pub async fn send_pcm(&mut self, samples: &[i16]) -> Result<(), actix_ws::Closed> {
+        let mulaw: Vec<u8> = samples.iter().map(|s| linear_to_mulaw(*s)).collect();
+        self.send_mulaw(&mulaw).await
+    }
+
+    /// Asks Twilio to report back with a `mark` event once all audio sent so
+    /// far has played.
+    pub async fn mark(&mut self, name: &str) -> Result<(), actix_ws::Closed> {
+        let stream_sid = self.stream_sid.clone();
+        self.send(&OutboundEvent::Mark {
+            stream_sid: &stream_sid,
+            mark: MarkPayload {
+                name: name.to_string(),
+            },
+        })
+        .await
+    }
+
+    /// Drops any buffered audio that has not played yet (e.g. on barge-in).
+    pub async fn clear(&mut self) -> Result<(), actix_ws::Closed> {
+        let stream_sid = self.stream_sid.clone();
+        self.send(&OutboundEvent::Clear {
+            stream_sid: &stream_sid,
+        })
+        .await
+    }
+}
+
+/// Async handler invoked for every event on every stream.
+pub type MediaStreamHandler = Arc<
+    dyn Fn(MediaStreamEvent, MediaStreamSender) -> Pin<Box<dyn Future<Output = ()>>> + Send + Sync,
+>;
+
+/// Wraps an async closure as a `MediaStreamHandler`.
+pub fn media_stream_handler<F, Fut>(handler: F) -> MediaStreamHandler
+where
+    F: Fn(MediaStreamEvent, MediaStreamSender) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    Arc::new(move |event, sender| Box::pin(handler(event, sender)))
+}
+
+async fn media_stream_socket(
+    req: HttpRequest,
+    body: web::Payload,
+    stream_url: web::Data<String>,
+    handler: web::Data<MediaStreamHandler>,
+) -> Result<HttpResponse, actix_web::Error> {
+    // Twilio signs the upgrade request over the `<Stream>` URL with no params.
+    if !is_signed_by_twilio(&req, stream_url.get_ref(), &HashMap::new()) {
+        return Ok(HttpResponse::Unauthorized().body("Invalid Twilio signature."));
+    }
+
+    let (response, session, mut messages) = actix_ws::handle(&req, body)?;
+    let handler = handler.get_ref().clone();
+
+    actix_web::rt::spawn(async move {
+        let mut sender = MediaStreamSender {
+            session,
+            stream_sid: String::new(),
+        };
+
+        while let Some(Ok(message)) = messages.recv().await {
+            match message {
+                Message::Text(text) => {
+                    let event =
+                        serde_json::from_str::<MediaStreamEvent>(&text).unwrap_or_else(|e| {
+                            MediaStreamEvent::Unrecognised {
+                                message: text.to_string(),
+                                error: e.to_string(),
+                            }
+                        });
+                    if let Some(stream_sid) = event.stream_sid() {
+                        sender.stream_sid = stream_sid.to_string();
+                    }
+                    let stopped = matches!(event, MediaStreamEvent::Stop { .. });
+                    handler(event, sender.clone()).await;
+                    if stopped {
+                        break;
+                    }
+                }
+                Message::Ping(bytes) => {
+                    let sent = sender.session.pong(&bytes).await;
+                    if sent.is_err() {
+                        break;
+                    }
+                }
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+
+        let _ = sender.session.close(None).await;
+    });
+
+    Ok(response)
+}
+
+/// Mounts the Media Streams WebSocket endpoint at `/twilio/stream`; point a
+/// `<Stream url="wss://…/twilio/stream">` at it. `stream_url` must be that
+/// exact URL, since the upgrade request's `X-Twilio-Signature` is checked
+/// against it.
+pub fn build_media_stream_scope(stream_url: &str, handler: MediaStreamHandler) -> Scope {
+    web::scope("/twilio/stream")
+        .app_data(web::Data::new(stream_url.to_string()))
+        .app_data(web::Data::new(handler))
+        .route("", web::get().to(media_stream_socket))
+}
+
+/// Decodes one G.711 μ-law byte to a 16-bit linear sample.
+pub fn mulaw_to_linear(byte: u8) -> i16 {
+    const BIAS: i16 = 0x84;
+    let byte = !byte;
+    let exponent = (byte >> 4) & 0x07;
+    let mantissa = (byte & 0x0F) as i16;
+    let magnitude = (((mantissa << 3) + BIAS) << exponent) - BIAS;
+    if byte & 0x80 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Encodes a 16-bit linear sample as G.711 μ-law.
+pub fn linear_to_mulaw(sample: i16) -> u8 {
+    const BIAS: i32 = 0x84;
+    const CLIP: i32 = 32635;
+
+    let mut value = sample as i32;
+    let sign = if value < 0 {
+        value = -value;
+        0x80
+    } else {
+        0
+    };
+    value = value.min(CLIP) + BIAS;
+
+    // Highest set bit above bit 7 picks the segment.
+    let exponent = (8 - (value as u16).leading_zeros()) as u8;
+    let mantissa = ((value >> (exponent + 3)) & 0x0F) as u8;
+    !(sign | (exponent << 4) | mantissa)
+}
@@ -0,0 +1,449 @@
+use crate::client::{send_json, TwilioApiError, TwilioClient};
+use crate::content::ContentVariables;
+use crate::phone::{PhoneNumber, PhoneNumberError};
+use reqwest::Method;
+use serde::{Deserialize, Deserializer};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Deserialize, Debug)]
+pub struct TwilioMessageResponse {
+    pub sid: String,
+    pub status: String,
+    pub to: String,
+    pub from: String,
+    /// Empty when Twilio omits the body or returns `null`, e.g. for some
+    /// content template messages.
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub body: String,
+    pub account_sid: String,
+    pub api_version: String,
+    pub date_created: Option<String>,
+    pub date_sent: Option<String>,
+    pub date_updated: Option<String>,
+    pub direction: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub messaging_service_sid: Option<String>,
+    #[serde(deserialize_with = "string_or_int")]
+    pub num_media: String,
+    #[serde(deserialize_with = "string_or_int")]
+    pub num_segments: String,
+    pub price: Option<String>,
+    pub price_unit: Option<String>,
+    pub uri: Option<String>,
+}
+
+fn null_as_empty<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+fn string_or_int<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde::de::{self, Visitor};
+
+    struct StringOrIntVisitor;
+
+    impl<'de> Visitor<'de> for StringOrIntVisitor {
+        type Value = String;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a string or integer")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<String, E>
+        where
+            E: de::Error,
+        {
+            Ok(value.to_string())
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<String, E>
+        where
+            E: de::Error,
+        {
+            Ok(value.to_string())
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<String, E>
+        where
+            E: de::Error,
+        {
+            Ok(value.to_string())
+        }
+    }
+
+    deserializer.deserialize_any(StringOrIntVisitor)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Channel {
+    Sms,
+    WhatsApp,
+    Rcs,
+    Messenger,
+}
+
+impl Channel {
+    /// Prefix Twilio expects on `To`/`From` for this channel.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Channel::Sms => "",
+            Channel::WhatsApp => "whatsapp:",
+            Channel::Rcs => "rcs:",
+            Channel::Messenger => "messenger:",
+        }
+    }
+
+    pub fn supports_interactive(&self) -> bool {
+        matches!(self, Channel::WhatsApp)
+    }
+
+    /// Whether a message with media can be delivered to `to`. Over SMS that
+    /// means MMS, which Twilio only delivers within the NANP territories in
+    /// `MMS_REGIONS`.
+    pub fn supports_media(&self, to: &Address) -> bool {
+        match (self, to) {
+            (Channel::Sms, Address::Sms(number)) => {
+                number.calling_code() == "1"
+                    && number
+                        .region()
+                        .is_some_and(|region| MMS_REGIONS.contains(&region))
+            }
+            _ => true,
+        }
+    }
+}
+
+/// NANP countries and territories (calling code `1`) that Twilio delivers MMS to.
+pub const MMS_REGIONS: &[&str] = &["US", "CA", "PR"];
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Channel::Sms => "SMS",
+            Channel::WhatsApp => "WhatsApp",
+            Channel::Rcs => "RCS",
+            Channel::Messenger => "Messenger",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A recipient or sender on a specific channel.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Address {
+    Sms(PhoneNumber),
+    WhatsApp(PhoneNumber),
+    Rcs(PhoneNumber),
+    /// A Messenger page ID or page-scoped user ID.
+    Messenger(String),
+}
+
+impl Address {
+    pub fn new(channel: Channel, number: PhoneNumber) -> Self {
+        match channel {
+            Channel::Sms => Address::Sms(number),
+            Channel::WhatsApp => Address::WhatsApp(number),
+            Channel::Rcs => Address::Rcs(number),
+            Channel::Messenger => Address::Messenger(number.e164()),
+        }
+    }
+
+    /// Parses a Twilio address such as `whatsapp:+14155238886`; addresses
+    /// without a prefix are SMS numbers.
+    pub fn parse(address: &str, default_region: Option<&str>) -> Result<Self, PhoneNumberError> {
+        let address = address.trim();
+        if let Some(id) = address.strip_prefix("messenger:") {
+            return Ok(Address::Messenger(id.to_string()));
+        }
+        if let Some(number) = address.strip_prefix("whatsapp:") {
+            return Ok(Address::WhatsApp(PhoneNumber::parse(
+                number,
+                default_region,
+            )?));
+        }
+        if let Some(number) = address.strip_prefix("rcs:") {
+            return Ok(Address::Rcs(PhoneNumber::parse(number, default_region)?));
+        }
+        Ok(Address::Sms(PhoneNumber::parse(address, default_region)?))
+    }
+
+    pub fn channel(&self) -> Channel {
+        match self {
+            Address::Sms(_) => Channel::Sms,
+            Address::WhatsApp(_) => Channel::WhatsApp,
+            Address::Rcs(_) => Channel::Rcs,
+            Address::Messenger(_) => Channel::Messenger,
+        }
+    }
+
+    pub fn phone_number(&self) -> Option<&PhoneNumber> {
+        match self {
+            Address::Sms(number) | Address::WhatsApp(number) | Address::Rcs(number) => Some(number),
+            Address::Messenger(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Address::Messenger(id) => write!(f, "{}{}", self.channel().prefix(), id),
+            Address::Sms(number) | Address::WhatsApp(number) | Address::Rcs(number) => {
+                write!(f, "{}{}", self.channel().prefix(), number)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelError {
+    /// The message uses something the recipient's channel cannot deliver.
+    Unsupported {
+        channel: Channel,
+        feature: &'static str,
+    },
+    /// No sender could be derived for the channel (Messenger needs a page ID).
+    MissingSender(Channel),
+}
+
+impl fmt::Display for ChannelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChannelError::Unsupported { channel, feature } => {
+                write!(
+                    f,
+                    "{} is not supported on {} for this recipient",
+                    feature, channel
+                )
+            }
+            ChannelError::MissingSender(channel) => {
+                write!(f, "no sender address configured for {}", channel)
+            }
+        }
+    }
+}
+
+impl Error for ChannelError {}
+
+/// Channel-independent message content for `TwilioClient::send_message`.
+#[derive(Debug, Clone, Default)]
+pub struct OutboundMessage {
+    pub body: Option<String>,
+    pub media_urls: Vec<String>,
+    pub content_sid: Option<String>,
+    pub content_variables: Option<ContentVariables>,
+    /// `PersistentAction` values, e.g. `geo:` locations (WhatsApp only).
+    pub persistent_actions: Vec<String>,
+    pub messaging_service_sid: Option<String>,
+    pub status_callback: Option<String>,
+    /// Overrides the sender derived from the client's `from_phone`.
+    pub from: Option<Address>,
+}
+
+impl OutboundMessage {
+    pub fn text(body: &str) -> Self {
+        Self {
+            body: Some(body.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn media(body: &str, media_url: &str) -> Self {
+        Self {
+            body: Some(body.to_string()),
+            media_urls: vec![media_url.to_string()],
+            ..Default::default()
+        }
+    }
+
+    pub fn content(content_sid: &str, content_variables: Option<&ContentVariables>) -> Self {
+        Self {
+            content_sid: Some(content_sid.to_string()),
+            content_variables: content_variables.cloned(),
+            ..Default::default()
+        }
+    }
+
+    /// Checks that every part of the message can be delivered to `to`.
+    pub fn validate_for(&self, to: &Address) -> Result<(), ChannelError> {
+        let channel = to.channel();
+        if !self.persistent_actions.is_empty() && !channel.supports_interactive() {
+            return Err(ChannelError::Unsupported {
+                channel,
+                feature: "persistent actions",
+            });
+        }
+        if !self.media_urls.is_empty() && !channel.supports_media(to) {
+            return Err(ChannelError::Unsupported {
+                channel,
+                feature: "media",
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Whether `error` from `send_message` guarantees nothing was delivered.
+fn was_not_sent(error: &(dyn Error + 'static)) -> bool {
+    error.is::<ChannelError>()
+        || error.is::<PhoneNumberError>()
+        || error
+            .downcast_ref::<TwilioApiError>()
+            .is_some_and(TwilioApiError::is_client_error)
+}
+
+impl TwilioClient {
+    /// Sender address for `channel`, derived from `from_phone`.
+    pub fn sender_address(&self, channel: Channel) -> Result<Address, Box<dyn Error>> {
+        if channel == Channel::Messenger {
+            return Err(ChannelError::MissingSender(channel).into());
+        }
+        Ok(Address::new(channel, self.from_number()?))
+    }
+
+    pub async fn fetch_message(
+        &self,
+        message_sid: &str,
+    ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+        let url = self.api_url(&format!("Messages/{}.json", message_sid));
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    /// Sends `message` to `to`, prefixing addresses for the recipient's
+    /// channel and rejecting content the channel cannot carry.
+    pub async fn send_message(
+        &self,
+        to: &Address,
+        message: &OutboundMessage,
+    ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+        message.validate_for(to)?;
+
+        let mut params: Vec<(&str, String)> = vec![("To", to.to_string())];
+
+        match (
+            &message.messaging_service_sid,
+            &message.from,
+            &self.messaging_service_sid,
+        ) {
+            (Some(service_sid), _, _) => params.push(("MessagingServiceSid", service_sid.clone())),
+            (None, Some(from), _) => params.push(("From", from.to_string())),
+            (None, None, Some(service_sid)) => {
+                params.push(("MessagingServiceSid", service_sid.clone()))
+            }
+            (None, None, None) => {
+                params.push(("From", self.sender_address(to.channel())?.to_string()))
+            }
+        }
+
+        if let Some(body) = &message.body {
+            params.push(("Body", body.clone()));
+        }
+        for media_url in &message.media_urls {
+            params.push(("MediaUrl", media_url.clone()));
+        }
+        if let Some(content_sid) = &message.content_sid {
+            params.push(("ContentSid", content_sid.clone()));
+        }
+        if let Some(variables) = &message.content_variables {
+            params.push(("ContentVariables", variables.to_json()));
+        }
+        for action in &message.persistent_actions {
+            params.push(("PersistentAction", action.clone()));
+        }
+        if let Some(callback) = &message.status_callback {
+            params.push(("StatusCallback", callback.clone()));
+        }
+
+        send_json(self.request(Method::POST, &self.base_url()).form(&params)).await
+    }
+
+    /// Sends the same message to each address in turn and returns the first
+    /// successful delivery, skipping channels that cannot carry the message.
+    ///
+    /// Only errors that mean the message was not sent move on to the next
+    /// address: a `ChannelError`, or a 4xx response from Twilio. Timeouts and
+    /// 5xx responses are returned as-is, since Twilio may already have
+    /// accepted the message and retrying elsewhere could deliver it twice.
+    pub async fn send_message_any(
+        &self,
+        addresses: &[Address],
+        message: &OutboundMessage,
+    ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+        let mut last_error: Box<dyn Error> = "no addresses to send to".into();
+        for address in addresses {
+            match self.send_message(address, message).await {
+                Ok(response) => return Ok(response),
+                Err(e) if was_not_sent(e.as_ref()) => last_error = e,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    fn api_error(status: StatusCode) -> TwilioApiError {
+        TwilioApiError {
+            status,
+            body: String::new(),
+        }
+    }
+
+    #[test]
+    fn falls_back_only_when_nothing_was_sent() {
+        let unsupported = ChannelError::Unsupported {
+            channel: Channel::Sms,
+            feature: "media",
+        };
+        assert!(was_not_sent(&unsupported));
+        assert!(was_not_sent(&api_error(StatusCode::BAD_REQUEST)));
+        assert!(!was_not_sent(&api_error(StatusCode::BAD_GATEWAY)));
+
+        let timeout: Box<dyn Error> = "operation timed out".into();
+        assert!(!was_not_sent(timeout.as_ref()));
+    }
+
+    #[test]
+    fn missing_or_null_bodies_read_as_empty() {
+        let response = |body: &str| {
+            let json = format!(
+                r#"{{"sid": "SM1", "status": "queued", "to": "+14155550100",
+                    "from": "+14155550101", "account_sid": "AC1",
+                    "api_version": "2010-04-01", "num_media": 0,
+                    "num_segments": "1"{}}}"#,
+                body
+            );
+            serde_json::from_str::<TwilioMessageResponse>(&json).unwrap()
+        };
+        assert_eq!(response(r#", "body": "Hi""#).body, "Hi");
+        assert_eq!(response(r#", "body": null"#).body, "");
+        assert_eq!(response("").body, "");
+    }
+
+    #[test]
+    fn mms_reaches_us_canada_and_puerto_rico_only() {
+        for (number, supported) in [
+            ("+14155550100", true),
+            ("+14165550100", true),
+            ("+17875550100", true),
+            ("+19395550100", true),
+            ("+18765550100", false),
+            ("+447700900123", false),
+        ] {
+            let to = Address::parse(number, None).unwrap();
+            assert_eq!(Channel::Sms.supports_media(&to), supported, "{}", number);
+            assert!(Channel::WhatsApp.supports_media(&to));
+        }
+    }
+}
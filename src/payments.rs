@@ -55,6 +55,7 @@ pub fn generate_upi_qr(link: &str) -> Result<String, Box<dyn Error>> {
 /// # Returns
 /// * Result<(), Box<dyn Error>>
 ///
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub async fn send_upi_payment_request(
     client: &TwilioClient,
     to: &str,
@@ -85,8 +86,5 @@ pub async fn send_upi_payment_request(
         msg
     };
 
-    send_whatsapp_text(client, to, &final_msg)
-        .await
-        .map(|_| ())
-        .map_err(|e| e.into())
+    send_whatsapp_text(client, to, &final_msg).await.map(|_| ())
 }
@@ -0,0 +1,474 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Numbering plan details needed to normalize a country's numbers.
+#[derive(Debug)]
+pub struct Region {
+    pub iso: &'static str,
+    pub calling_code: &'static str,
+    pub trunk_prefix: Option<&'static str>,
+    /// Allowed length of the national significant number (min, max).
+    pub national_length: (usize, usize),
+}
+
+const fn region(
+    iso: &'static str,
+    calling_code: &'static str,
+    trunk_prefix: Option<&'static str>,
+    min: usize,
+    max: usize,
+) -> Region {
+    Region {
+        iso,
+        calling_code,
+        trunk_prefix,
+        national_length: (min, max),
+    }
+}
+
+/// Regions sharing a calling code (e.g. `US`/`CA`) list the primary one first.
+pub static REGIONS: &[Region] = &[
+    region("US", "1", Some("1"), 10, 10),
+    region("CA", "1", Some("1"), 10, 10),
+    region("GB", "44", Some("0"), 9, 10),
+    region("IN", "91", Some("0"), 10, 10),
+    region("AU", "61", Some("0"), 9, 9),
+    region("NZ", "64", Some("0"), 8, 10),
+    region("IE", "353", Some("0"), 7, 9),
+    region("DE", "49", Some("0"), 6, 13),
+    region("FR", "33", Some("0"), 9, 9),
+    region("ES", "34", None, 9, 9),
+    region("IT", "39", None, 6, 11),
+    region("PT", "351", None, 9, 9),
+    region("NL", "31", Some("0"), 9, 9),
+    region("BE", "32", Some("0"), 8, 9),
+    region("CH", "41", Some("0"), 9, 9),
+    region("AT", "43", Some("0"), 4, 13),
+    region("SE", "46", Some("0"), 7, 9),
+    region("NO", "47", None, 8, 8),
+    region("DK", "45", None, 8, 8),
+    region("FI", "358", Some("0"), 5, 12),
+    region("PL", "48", None, 9, 9),
+    region("RU", "7", Some("8"), 10, 10),
+    region("TR", "90", Some("0"), 10, 10),
+    region("IL", "972", Some("0"), 8, 9),
+    region("AE", "971", Some("0"), 8, 9),
+    region("SA", "966", Some("0"), 8, 9),
+    region("EG", "20", Some("0"), 8, 10),
+    region("NG", "234", Some("0"), 8, 10),
+    region("KE", "254", Some("0"), 9, 9),
+    region("ZA", "27", Some("0"), 9, 9),
+    region("BR", "55", Some("0"), 10, 11),
+    region("MX", "52", None, 10, 10),
+    region("AR", "54", Some("0"), 10, 11),
+    region("CO", "57", None, 10, 10),
+    region("CL", "56", None, 9, 9),
+    region("CN", "86", Some("0"), 7, 11),
+    region("JP", "81", Some("0"), 9, 10),
+    region("KR", "82", Some("0"), 8, 10),
+    region("HK", "852", None, 8, 8),
+    region("SG", "65", None, 8, 8),
+    region("MY", "60", Some("0"), 8, 10),
+    region("ID", "62", Some("0"), 8, 12),
+    region("PH", "63", Some("0"), 10, 10),
+    region("TH", "66", Some("0"), 8, 9),
+    region("VN", "84", Some("0"), 9, 10),
+    region("PK", "92", Some("0"), 9, 10),
+    region("BD", "880", Some("0"), 10, 10),
+    region("LK", "94", Some("0"), 9, 9),
+    region("NP", "977", Some("0"), 8, 10),
+];
+
+/// NANP area codes outside the US. Every other `+1` area code is treated
+/// as `US`.
+static NANP_AREA_CODES: &[(&str, &[&str])] = &[
+    (
+        "CA",
+        &[
+            "204", "226", "236", "249", "250", "257", "263", "289", "306", "343", "354", "365",
+            "367", "368", "382", "403", "416", "418", "428", "431", "437", "438", "450", "460",
+            "468", "474", "506", "514", "519", "548", "579", "581", "584", "587", "604", "613",
+            "639", "647", "672", "683", "705", "709", "742", "753", "778", "780", "782", "807",
+            "819", "825", "867", "873", "879", "902", "905", "942",
+        ],
+    ),
+    ("AG", &["268"]),
+    ("AI", &["264"]),
+    ("AS", &["684"]),
+    ("BB", &["246"]),
+    ("BM", &["441"]),
+    ("BS", &["242"]),
+    ("DM", &["767"]),
+    ("DO", &["809", "829", "849"]),
+    ("GD", &["473"]),
+    ("GU", &["671"]),
+    ("JM", &["658", "876"]),
+    ("KN", &["869"]),
+    ("KY", &["345"]),
+    ("LC", &["758"]),
+    ("MP", &["670"]),
+    ("MS", &["664"]),
+    ("PR", &["787", "939"]),
+    ("SX", &["721"]),
+    ("TC", &["649"]),
+    ("TT", &["868"]),
+    ("VC", &["784"]),
+    ("VG", &["284"]),
+    ("VI", &["340"]),
+];
+
+/// ISO code of the NANP country that owns `national_number`'s area code.
+fn nanp_region(national_number: &str) -> &'static str {
+    let area_code = national_number.get(..3).unwrap_or_default();
+    NANP_AREA_CODES
+        .iter()
+        .find(|(_, codes)| codes.contains(&area_code))
+        .map_or("US", |(iso, _)| iso)
+}
+
+pub fn find_region(iso: &str) -> Option<&'static Region> {
+    REGIONS.iter().find(|r| r.iso.eq_ignore_ascii_case(iso))
+}
+
+fn find_calling_code(digits: &str) -> Option<&'static Region> {
+    // Calling codes are prefix-free, so the first (longest) match wins.
+    (1..=3).rev().find_map(|len| {
+        let prefix = digits.get(..len)?;
+        REGIONS.iter().find(|r| r.calling_code == prefix)
+    })
+}
+
+/// Channel prefixes Twilio uses on `To`/`From` addresses.
+pub const ADDRESS_PREFIXES: &[&str] = &["whatsapp:", "messenger:", "rcs:", "sms:", "tel:"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhoneNumberError {
+    Empty,
+    InvalidCharacter(char),
+    /// A national-format number was given without a default region.
+    MissingRegion(String),
+    UnknownRegion(String),
+    /// The calling code is not in `REGIONS` and the number is not a
+    /// plausible E.164 length either.
+    UnknownCallingCode(String),
+    InvalidLength {
+        region: &'static str,
+        length: usize,
+        expected: (usize, usize),
+    },
+}
+
+impl fmt::Display for PhoneNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PhoneNumberError::Empty => write!(f, "phone number is empty"),
+            PhoneNumberError::InvalidCharacter(c) => {
+                write!(f, "invalid character '{}' in phone number", c)
+            }
+            PhoneNumberError::MissingRegion(input) => write!(
+                f,
+                "'{}' is not in international format and no default region is set",
+                input
+            ),
+            PhoneNumberError::UnknownRegion(iso) => write!(f, "unknown region '{}'", iso),
+            PhoneNumberError::UnknownCallingCode(digits) => {
+                write!(
+                    f,
+                    "'+{}' has an unknown calling code and is not a valid E.164 length",
+                    digits
+                )
+            }
+            PhoneNumberError::InvalidLength {
+                region,
+                length,
+                expected: (min, max),
+            } if min == max => write!(
+                f,
+                "{} numbers have {} digits after the country code, got {}",
+                region, min, length
+            ),
+            PhoneNumberError::InvalidLength {
+                region,
+                length,
+                expected: (min, max),
+            } => write!(
+                f,
+                "{} numbers have {}-{} digits after the country code, got {}",
+                region, min, max, length
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PhoneNumberError {}
+
+/// Length limits of an E.164 number (calling code included), used for
+/// numbers whose calling code is not in `REGIONS`.
+pub const E164_LENGTH: (usize, usize) = (7, 15);
+
+/// A phone number normalized to E.164.
+///
+/// Numbers whose calling code is not in `REGIONS` are kept as given, with an
+/// empty `calling_code`. Senders may also be alphanumeric IDs or short codes
+/// (see `parse_sender`), which are passed to Twilio unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhoneNumber {
+    calling_code: &'static str,
+    national_number: String,
+    sender_id: bool,
+}
+
+impl PhoneNumber {
+    /// Parses a number written by a human, e.g. `07569 785621` with
+    /// `default_region` `IN`, or `+91-75697-85621` with any region.
+    ///
+    /// Channel prefixes such as `whatsapp:` are ignored.
+    pub fn parse(input: &str, default_region: Option<&str>) -> Result<Self, PhoneNumberError> {
+        let trimmed = strip_address_prefix(input.trim());
+
+        let mut digits = String::with_capacity(trimmed.len());
+        let mut international = false;
+        for (i, c) in trimmed.chars().enumerate() {
+            match c {
+                '0'..='9' => digits.push(c),
+                '+' if i == 0 => international = true,
+                ' ' | '-' | '.' | '(' | ')' | '/' => {}
+                _ => return Err(PhoneNumberError::InvalidCharacter(c)),
+            }
+        }
+
+        if digits.is_empty() {
+            return Err(PhoneNumberError::Empty);
+        }
+
+        if !international {
+            if let Some(rest) = digits.strip_prefix("00") {
+                digits = rest.to_string();
+                international = true;
+            }
+        }
+
+        if international {
+            let region = match find_calling_code(&digits) {
+                Some(region) => region,
+                None => return Self::opaque(digits),
+            };
+            let national = digits[region.calling_code.len()..].to_string();
+            return Self::validated(region, national);
+        }
+
+        let iso = default_region.ok_or_else(|| PhoneNumberError::MissingRegion(input.into()))?;
+        let region =
+            find_region(iso).ok_or_else(|| PhoneNumberError::UnknownRegion(iso.to_string()))?;
+
+        let (min, _) = region.national_length;
+        let national = match region.trunk_prefix {
+            Some(trunk) if digits.starts_with(trunk) && digits.len() - trunk.len() >= min => {
+                digits[trunk.len()..].to_string()
+            }
+            _ => digits,
+        };
+
+        Self::validated(region, national)
+    }
+
+    /// Parses the sender configured in `TWILIO_PHONE_NUMBER`: a phone number,
+    /// or else an alphanumeric sender ID (up to 11 letters, digits and
+    /// spaces) or a short code (3-8 digits), which are used as-is.
+    pub fn parse_sender(
+        input: &str,
+        default_region: Option<&str>,
+    ) -> Result<Self, PhoneNumberError> {
+        let trimmed = strip_address_prefix(input.trim());
+        let error = match Self::parse(trimmed, default_region) {
+            Ok(number) => return Ok(number),
+            Err(error) => error,
+        };
+
+        let is_short_code =
+            (3..=8).contains(&trimmed.len()) && trimmed.chars().all(|c| c.is_ascii_digit());
+        let is_alphanumeric_id = (1..=11).contains(&trimmed.len())
+            && trimmed.chars().any(|c| c.is_ascii_alphabetic())
+            && trimmed
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == ' ');
+        if is_short_code || is_alphanumeric_id {
+            return Ok(Self {
+                calling_code: "",
+                national_number: trimmed.to_string(),
+                sender_id: true,
+            });
+        }
+        Err(error)
+    }
+
+    /// An international number with a calling code missing from `REGIONS`,
+    /// accepted on length alone.
+    fn opaque(digits: String) -> Result<Self, PhoneNumberError> {
+        let (min, max) = E164_LENGTH;
+        if digits.len() < min || digits.len() > max {
+            return Err(PhoneNumberError::UnknownCallingCode(digits));
+        }
+        Ok(Self {
+            calling_code: "",
+            national_number: digits,
+            sender_id: false,
+        })
+    }
+
+    fn validated(region: &'static Region, national: String) -> Result<Self, PhoneNumberError> {
+        let (min, max) = region.national_length;
+        if national.len() < min || national.len() > max {
+            return Err(PhoneNumberError::InvalidLength {
+                region: region.iso,
+                length: national.len(),
+                expected: region.national_length,
+            });
+        }
+        Ok(Self {
+            calling_code: region.calling_code,
+            national_number: national,
+            sender_id: false,
+        })
+    }
+
+    /// Empty when the calling code is not in `REGIONS` or this is a sender
+    /// ID.
+    pub fn calling_code(&self) -> &str {
+        self.calling_code
+    }
+
+    /// Whether this is an alphanumeric sender ID or short code rather than
+    /// a phone number.
+    pub fn is_sender_id(&self) -> bool {
+        self.sender_id
+    }
+
+    /// All digits after the `+` when the calling code is unknown.
+    pub fn national_number(&self) -> &str {
+        &self.national_number
+    }
+
+    /// ISO code of the number's country: the primary region for its calling
+    /// code, or for `+1` numbers the country owning the area code.
+    pub fn region(&self) -> Option<&'static str> {
+        if self.calling_code == "1" && !self.sender_id {
+            return Some(nanp_region(&self.national_number));
+        }
+        find_calling_code(self.calling_code).map(|r| r.iso)
+    }
+
+    /// The number in E.164 form; sender IDs are returned unchanged.
+    pub fn e164(&self) -> String {
+        if self.sender_id {
+            return self.national_number.clone();
+        }
+        format!("+{}{}", self.calling_code, self.national_number)
+    }
+
+    pub fn whatsapp_address(&self) -> String {
+        format!("whatsapp:{}", self.e164())
+    }
+
+    pub fn rcs_address(&self) -> String {
+        format!("rcs:{}", self.e164())
+    }
+
+    pub fn messenger_address(&self) -> String {
+        format!("messenger:{}", self.e164())
+    }
+}
+
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.e164())
+    }
+}
+
+/// Parses numbers already in international form (`+…` or `00…`).
+impl FromStr for PhoneNumber {
+    type Err = PhoneNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PhoneNumber::parse(s, None)
+    }
+}
+
+/// Removes a leading channel prefix such as `whatsapp:` from an address.
+pub fn strip_address_prefix(address: &str) -> &str {
+    ADDRESS_PREFIXES
+        .iter()
+        .find_map(|prefix| address.strip_prefix(prefix))
+        .unwrap_or(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_calling_codes_are_validated() {
+        let number = PhoneNumber::parse("+91 75697 85621", None).unwrap();
+        assert_eq!(number.calling_code(), "91");
+        assert_eq!(number.e164(), "+917569785621");
+        assert!(matches!(
+            PhoneNumber::parse("+91 7569", None),
+            Err(PhoneNumberError::InvalidLength { region: "IN", .. })
+        ));
+    }
+
+    #[test]
+    fn nanp_numbers_resolve_to_their_country() {
+        let region = |input: &str| PhoneNumber::parse(input, None).unwrap().region();
+        assert_eq!(region("+1 212 555 0100"), Some("US"));
+        assert_eq!(region("+1 416 555 0100"), Some("CA"));
+        assert_eq!(region("+1 876 555 0100"), Some("JM"));
+        assert_eq!(region("+44 20 7946 0958"), Some("GB"));
+    }
+
+    #[test]
+    fn unknown_calling_codes_pass_through_as_e164() {
+        for input in ["+30 21 0123 4567", "+380 44 123 4567", "+420 601 123 456"] {
+            let number = PhoneNumber::parse(input, None).unwrap();
+            assert_eq!(number.calling_code(), "");
+            assert_eq!(number.region(), None);
+            assert_eq!(number.e164(), format!("+{}", number.national_number()));
+        }
+        assert_eq!(
+            PhoneNumber::parse("whatsapp:+420601123456", None)
+                .unwrap()
+                .to_string(),
+            "+420601123456"
+        );
+    }
+
+    #[test]
+    fn unknown_calling_codes_still_need_a_plausible_length() {
+        assert!(matches!(
+            PhoneNumber::parse("+380 12", None),
+            Err(PhoneNumberError::UnknownCallingCode(_))
+        ));
+        assert!(matches!(
+            PhoneNumber::parse("+380 1234 5678 9012 34", None),
+            Err(PhoneNumberError::UnknownCallingCode(_))
+        ));
+    }
+
+    #[test]
+    fn senders_may_be_alphanumeric_ids_or_short_codes() {
+        let brand = PhoneNumber::parse_sender("MyBrand", None).unwrap();
+        assert!(brand.is_sender_id());
+        assert_eq!(brand.e164(), "MyBrand");
+
+        let short_code = PhoneNumber::parse_sender("89898", None).unwrap();
+        assert!(short_code.is_sender_id());
+        assert_eq!(short_code.to_string(), "89898");
+
+        let number = PhoneNumber::parse_sender("+14155238886", None).unwrap();
+        assert!(!number.is_sender_id());
+        assert_eq!(number.e164(), "+14155238886");
+
+        assert!(PhoneNumber::parse("MyBrand", None).is_err());
+        assert!(PhoneNumber::parse_sender("Not a valid sender!", None).is_err());
+    }
+}
@@ -0,0 +1,328 @@
+use crate::client::{send_empty, send_json, TwilioClient};
+use reqwest::Method;
+use serde::Deserialize;
+use std::error::Error;
+
+/// What a number can do. The AvailablePhoneNumbers API reports `SMS`/`MMS`
+/// in upper case, IncomingPhoneNumbers in lower case.
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberCapabilities {
+    #[serde(default)]
+    pub voice: bool,
+    #[serde(default, alias = "SMS")]
+    pub sms: bool,
+    #[serde(default, alias = "MMS")]
+    pub mms: bool,
+    #[serde(default)]
+    pub fax: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct IncomingPhoneNumber {
+    pub sid: String,
+    pub account_sid: String,
+    pub phone_number: String,
+    pub friendly_name: Option<String>,
+    #[serde(default)]
+    pub capabilities: NumberCapabilities,
+    pub sms_url: Option<String>,
+    pub sms_method: Option<String>,
+    pub sms_fallback_url: Option<String>,
+    pub sms_application_sid: Option<String>,
+    pub voice_url: Option<String>,
+    pub voice_method: Option<String>,
+    pub voice_fallback_url: Option<String>,
+    pub voice_application_sid: Option<String>,
+    pub status_callback: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct IncomingPhoneNumberPage {
+    incoming_phone_numbers: Vec<IncomingPhoneNumber>,
+}
+
+/// Webhook configuration applied when buying or updating a number; unset
+/// fields are left unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct NumberConfig {
+    pub friendly_name: Option<String>,
+    pub sms_url: Option<String>,
+    pub sms_method: Option<String>,
+    pub sms_fallback_url: Option<String>,
+    pub voice_url: Option<String>,
+    pub voice_method: Option<String>,
+    pub voice_fallback_url: Option<String>,
+    /// Call status callback for calls to the number.
+    pub status_callback: Option<String>,
+}
+
+impl NumberConfig {
+    fn push_params(&self, params: &mut Vec<(&'static str, String)>) {
+        let fields = [
+            ("FriendlyName", &self.friendly_name),
+            ("SmsUrl", &self.sms_url),
+            ("SmsMethod", &self.sms_method),
+            ("SmsFallbackUrl", &self.sms_fallback_url),
+            ("VoiceUrl", &self.voice_url),
+            ("VoiceMethod", &self.voice_method),
+            ("VoiceFallbackUrl", &self.voice_fallback_url),
+            ("StatusCallback", &self.status_callback),
+        ];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                params.push((name, value.clone()));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberType {
+    Local,
+    TollFree,
+    Mobile,
+}
+
+impl NumberType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberType::Local => "Local",
+            NumberType::TollFree => "TollFree",
+            NumberType::Mobile => "Mobile",
+        }
+    }
+}
+
+/// Criteria for `TwilioClient::search_available_numbers`; unset fields are
+/// not sent.
+#[derive(Debug, Clone)]
+pub struct AvailableNumberSearch {
+    /// ISO 3166-1 alpha-2 country code, e.g. `US`.
+    pub country: String,
+    pub number_type: NumberType,
+    pub area_code: Option<u32>,
+    /// Digits or letters the number must contain; `*` matches any digit.
+    pub contains: Option<String>,
+    pub sms_enabled: Option<bool>,
+    pub mms_enabled: Option<bool>,
+    pub voice_enabled: Option<bool>,
+    /// State or province abbreviation (US/CA only).
+    pub in_region: Option<String>,
+    pub in_postal_code: Option<String>,
+    pub page_size: Option<u32>,
+}
+
+impl AvailableNumberSearch {
+    pub fn new(country: &str, number_type: NumberType) -> Self {
+        Self {
+            country: country.to_uppercase(),
+            number_type,
+            area_code: None,
+            contains: None,
+            sms_enabled: None,
+            mms_enabled: None,
+            voice_enabled: None,
+            in_region: None,
+            in_postal_code: None,
+            page_size: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AvailablePhoneNumber {
+    pub phone_number: String,
+    pub friendly_name: Option<String>,
+    pub iso_country: Option<String>,
+    pub region: Option<String>,
+    pub locality: Option<String>,
+    pub postal_code: Option<String>,
+    pub rate_center: Option<String>,
+    pub lata: Option<String>,
+    pub latitude: Option<String>,
+    pub longitude: Option<String>,
+    /// `none`, `any`, `local` or `foreign`.
+    pub address_requirements: Option<String>,
+    #[serde(default)]
+    pub beta: bool,
+    #[serde(default)]
+    pub capabilities: NumberCapabilities,
+}
+
+#[derive(Deserialize, Debug)]
+struct AvailablePhoneNumberPage {
+    available_phone_numbers: Vec<AvailablePhoneNumber>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OutgoingCallerId {
+    pub sid: String,
+    pub account_sid: String,
+    pub phone_number: String,
+    pub friendly_name: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct OutgoingCallerIdPage {
+    outgoing_caller_ids: Vec<OutgoingCallerId>,
+}
+
+/// Twilio calls the number and the owner keys in `validation_code` to prove
+/// ownership.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ValidationRequest {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub phone_number: String,
+    pub friendly_name: Option<String>,
+    pub validation_code: String,
+}
+
+impl TwilioClient {
+    pub async fn list_incoming_numbers(&self) -> Result<Vec<IncomingPhoneNumber>, Box<dyn Error>> {
+        let url = self.api_url("IncomingPhoneNumbers.json");
+        let page: IncomingPhoneNumberPage = send_json(self.request(Method::GET, &url)).await?;
+        Ok(page.incoming_phone_numbers)
+    }
+
+    /// Looks up one of the account's numbers by its E.164 value.
+    pub async fn find_incoming_number(
+        &self,
+        phone_number: &str,
+    ) -> Result<Option<IncomingPhoneNumber>, Box<dyn Error>> {
+        let query = [("PhoneNumber", self.parse_number(phone_number)?.e164())];
+        let url = self.api_url("IncomingPhoneNumbers.json");
+        let page: IncomingPhoneNumberPage =
+            send_json(self.request(Method::GET, &url).query(&query)).await?;
+        Ok(page.incoming_phone_numbers.into_iter().next())
+    }
+
+    pub async fn fetch_incoming_number(
+        &self,
+        number_sid: &str,
+    ) -> Result<IncomingPhoneNumber, Box<dyn Error>> {
+        let url = self.api_url(&format!("IncomingPhoneNumbers/{}.json", number_sid));
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    /// Buys `phone_number` (usually from `search_available_numbers`) and
+    /// applies `config` to it.
+    pub async fn buy_number(
+        &self,
+        phone_number: &str,
+        config: &NumberConfig,
+    ) -> Result<IncomingPhoneNumber, Box<dyn Error>> {
+        let mut params = vec![("PhoneNumber", self.parse_number(phone_number)?.e164())];
+        config.push_params(&mut params);
+        send_json(
+            self.request(Method::POST, &self.api_url("IncomingPhoneNumbers.json"))
+                .form(&params),
+        )
+        .await
+    }
+
+    pub async fn update_incoming_number(
+        &self,
+        number_sid: &str,
+        config: &NumberConfig,
+    ) -> Result<IncomingPhoneNumber, Box<dyn Error>> {
+        let mut params = Vec::new();
+        config.push_params(&mut params);
+        let url = self.api_url(&format!("IncomingPhoneNumbers/{}.json", number_sid));
+        send_json(self.request(Method::POST, &url).form(&params)).await
+    }
+
+    /// Releases the number from the account. This cannot be undone.
+    pub async fn release_number(&self, number_sid: &str) -> Result<(), Box<dyn Error>> {
+        let url = self.api_url(&format!("IncomingPhoneNumbers/{}.json", number_sid));
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+
+    pub async fn search_available_numbers(
+        &self,
+        search: &AvailableNumberSearch,
+    ) -> Result<Vec<AvailablePhoneNumber>, Box<dyn Error>> {
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(area_code) = search.area_code {
+            query.push(("AreaCode", area_code.to_string()));
+        }
+        if let Some(contains) = &search.contains {
+            query.push(("Contains", contains.clone()));
+        }
+        if let Some(enabled) = search.sms_enabled {
+            query.push(("SmsEnabled", enabled.to_string()));
+        }
+        if let Some(enabled) = search.mms_enabled {
+            query.push(("MmsEnabled", enabled.to_string()));
+        }
+        if let Some(enabled) = search.voice_enabled {
+            query.push(("VoiceEnabled", enabled.to_string()));
+        }
+        if let Some(region) = &search.in_region {
+            query.push(("InRegion", region.clone()));
+        }
+        if let Some(postal_code) = &search.in_postal_code {
+            query.push(("InPostalCode", postal_code.clone()));
+        }
+        if let Some(page_size) = search.page_size {
+            query.push(("PageSize", page_size.to_string()));
+        }
+
+        let url = self.api_url(&format!(
+            "AvailablePhoneNumbers/{}/{}.json",
+            search.country,
+            search.number_type.as_str()
+        ));
+        let page: AvailablePhoneNumberPage =
+            send_json(self.request(Method::GET, &url).query(&query)).await?;
+        Ok(page.available_phone_numbers)
+    }
+
+    pub async fn list_outgoing_caller_ids(&self) -> Result<Vec<OutgoingCallerId>, Box<dyn Error>> {
+        let url = self.api_url("OutgoingCallerIds.json");
+        let page: OutgoingCallerIdPage = send_json(self.request(Method::GET, &url)).await?;
+        Ok(page.outgoing_caller_ids)
+    }
+
+    /// Starts verifying a number as a caller ID. Show the returned
+    /// `validation_code` to the owner, who enters it when Twilio calls.
+    pub async fn request_caller_id_validation(
+        &self,
+        phone_number: &str,
+        friendly_name: Option<&str>,
+    ) -> Result<ValidationRequest, Box<dyn Error>> {
+        let mut params = vec![("PhoneNumber", self.parse_number(phone_number)?.e164())];
+        if let Some(name) = friendly_name {
+            params.push(("FriendlyName", name.to_string()));
+        }
+        send_json(
+            self.request(Method::POST, &self.api_url("ValidationRequests.json"))
+                .form(&params),
+        )
+        .await
+    }
+
+    pub async fn rename_outgoing_caller_id(
+        &self,
+        caller_id_sid: &str,
+        friendly_name: &str,
+    ) -> Result<OutgoingCallerId, Box<dyn Error>> {
+        let url = self.api_url(&format!("OutgoingCallerIds/{}.json", caller_id_sid));
+        let params = [("FriendlyName", friendly_name)];
+        send_json(self.request(Method::POST, &url).form(&params)).await
+    }
+
+    pub async fn delete_outgoing_caller_id(
+        &self,
+        caller_id_sid: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let url = self.api_url(&format!("OutgoingCallerIds/{}.json", caller_id_sid));
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+}
@@ -0,0 +1,546 @@
+use crate::client::{send_json, TwilioClient};
+use crate::decimal::Decimal;
+use crate::encoding::analyze_sms;
+use crate::messaging::TwilioMessageResponse;
+use crate::phone::PhoneNumber;
+use reqwest::Method;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+const PRICING_API: &str = "https://pricing.twilio.com";
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NumberTypePrice {
+    pub number_type: String,
+    pub base_price: Option<String>,
+    pub current_price: Option<String>,
+}
+
+/// Type of the Twilio number a message is sent *from*; outbound SMS prices
+/// are listed per sender type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SenderNumberType {
+    Local,
+    Mobile,
+    TollFree,
+    ShortCode,
+}
+
+impl SenderNumberType {
+    /// The `number_type` value used by the Pricing API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SenderNumberType::Local => "local",
+            SenderNumberType::Mobile => "mobile",
+            SenderNumberType::TollFree => "toll free",
+            SenderNumberType::ShortCode => "shortcode",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OutboundSmsPrice {
+    pub carrier: Option<String>,
+    pub mcc: Option<String>,
+    pub mnc: Option<String>,
+    pub prices: Vec<NumberTypePrice>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MessagingCountryPricing {
+    pub country: String,
+    pub iso_country: String,
+    #[serde(default)]
+    pub outbound_sms_prices: Vec<OutboundSmsPrice>,
+    #[serde(default)]
+    pub inbound_sms_prices: Vec<NumberTypePrice>,
+    pub price_unit: String,
+}
+
+impl MessagingCountryPricing {
+    /// Highest current per-segment price for `number_type` across carriers,
+    /// so estimates err on the side of overspending.
+    pub fn outbound_price(&self, number_type: &str) -> Option<Decimal> {
+        self.outbound_sms_prices
+            .iter()
+            .flat_map(|carrier| carrier.prices.iter())
+            .filter(|price| price.number_type == number_type)
+            .filter_map(|price| parse_price(price.current_price.as_deref()?))
+            .max()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OutboundPrefixPrice {
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+    #[serde(default)]
+    pub origination_prefixes: Vec<String>,
+    pub friendly_name: Option<String>,
+    pub base_price: Option<String>,
+    pub current_price: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct VoiceCountryPricing {
+    pub country: String,
+    pub iso_country: String,
+    #[serde(default)]
+    pub outbound_prefix_prices: Vec<OutboundPrefixPrice>,
+    #[serde(default)]
+    pub inbound_call_prices: Vec<NumberTypePrice>,
+    pub price_unit: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OutboundCallPrice {
+    #[serde(default)]
+    pub origination_prefixes: Vec<String>,
+    pub base_price: Option<String>,
+    pub current_price: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct InboundCallPrice {
+    pub number_type: Option<String>,
+    pub base_price: Option<String>,
+    pub current_price: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct VoiceNumberPricing {
+    pub destination_number: Option<String>,
+    pub origination_number: Option<String>,
+    pub country: String,
+    pub iso_country: String,
+    #[serde(default)]
+    pub outbound_call_prices: Vec<OutboundCallPrice>,
+    pub inbound_call_price: Option<InboundCallPrice>,
+    pub price_unit: String,
+}
+
+/// Parses a Twilio price string exactly, keeping its sign: list prices are
+/// positive, while the price on a finalized message is negative (a debit).
+pub fn parse_price(price: &str) -> Option<Decimal> {
+    price.parse().ok()
+}
+
+impl TwilioClient {
+    pub async fn fetch_messaging_pricing(
+        &self,
+        iso_country: &str,
+    ) -> Result<MessagingCountryPricing, Box<dyn Error>> {
+        let url = format!("{}/v1/Messaging/Countries/{}", PRICING_API, iso_country);
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    pub async fn fetch_voice_pricing(
+        &self,
+        iso_country: &str,
+    ) -> Result<VoiceCountryPricing, Box<dyn Error>> {
+        let url = format!("{}/v2/Voice/Countries/{}", PRICING_API, iso_country);
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    /// Per-minute price of calling `destination`, optionally from a specific
+    /// origination number.
+    pub async fn fetch_voice_number_pricing(
+        &self,
+        destination: &PhoneNumber,
+        origination: Option<&PhoneNumber>,
+    ) -> Result<VoiceNumberPricing, Box<dyn Error>> {
+        let url = format!(
+            "{}/v2/Voice/Numbers/{}",
+            PRICING_API,
+            urlencoding::encode(&destination.e164())
+        );
+        let mut request = self.request(Method::GET, &url);
+        if let Some(origination) = origination {
+            request = request.query(&[("OriginationNumber", origination.e164())]);
+        }
+        send_json(request).await
+    }
+}
+
+/// Messaging prices by ISO country, loaded once and reused for estimates.
+#[derive(Debug, Clone, Default)]
+pub struct PriceTable {
+    countries: HashMap<String, MessagingCountryPricing>,
+}
+
+impl PriceTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pricing: MessagingCountryPricing) {
+        self.countries
+            .insert(pricing.iso_country.to_uppercase(), pricing);
+    }
+
+    pub fn get(&self, iso_country: &str) -> Option<&MessagingCountryPricing> {
+        self.countries.get(&iso_country.to_uppercase())
+    }
+
+    /// Fetches and caches pricing for each country not already loaded.
+    pub async fn load(
+        &mut self,
+        client: &TwilioClient,
+        iso_countries: &[&str],
+    ) -> Result<(), Box<dyn Error>> {
+        for iso in iso_countries {
+            if self.get(iso).is_none() {
+                let pricing = client.fetch_messaging_pricing(iso).await?;
+                self.insert(pricing);
+            }
+        }
+        Ok(())
+    }
+
+    /// Estimates the cost of sending `body` to `to` as SMS from a Twilio
+    /// number of type `sender`. Returns `None` when the destination country
+    /// is not in the table or has no price for that sender type.
+    pub fn estimate_sms(
+        &self,
+        to: &PhoneNumber,
+        sender: SenderNumberType,
+        body: &str,
+    ) -> Option<CostEstimate> {
+        let pricing = self.get(to.region()?)?;
+        let unit_price = pricing.outbound_price(sender.as_str())?;
+        let segments = analyze_sms(body).segments;
+        Some(CostEstimate {
+            iso_country: pricing.iso_country.clone(),
+            segments,
+            unit_price,
+            total: unit_price.checked_mul(segments as i128)?,
+            price_unit: pricing.price_unit.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostEstimate {
+    pub iso_country: String,
+    pub segments: usize,
+    pub unit_price: Decimal,
+    pub total: Decimal,
+    pub price_unit: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostReconciliation {
+    pub message_sid: String,
+    pub estimated: Decimal,
+    /// What the message cost: its finalized price with the debit sign
+    /// flipped, so it compares directly with `estimated`.
+    pub actual: Decimal,
+    pub price_unit: String,
+}
+
+impl CostReconciliation {
+    /// Positive when the message cost more than estimated.
+    pub fn difference(&self) -> Decimal {
+        self.actual - self.estimated
+    }
+}
+
+/// Compares an estimate with the price on a message. Returns `None` until
+/// Twilio has finalized the price.
+pub fn reconcile(
+    estimate: &CostEstimate,
+    message: &TwilioMessageResponse,
+) -> Option<CostReconciliation> {
+    let actual = parse_price(message.price.as_deref()?)?.checked_neg()?;
+    Some(CostReconciliation {
+        message_sid: message.sid.clone(),
+        estimated: estimate.total,
+        actual,
+        price_unit: message
+            .price_unit
+            .clone()
+            .unwrap_or_else(|| estimate.price_unit.clone()),
+    })
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CampaignSpend {
+    pub messages: usize,
+    pub estimated_total: Decimal,
+    /// Sum of finalized costs; only covers `reconciled` messages.
+    pub actual_total: Decimal,
+    pub reconciled: usize,
+    pub price_unit: Option<String>,
+}
+
+impl CampaignSpend {
+    pub fn pending(&self) -> usize {
+        self.messages - self.reconciled
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpendError {
+    /// A campaign's totals are kept in one currency; this amount is in
+    /// another.
+    CurrencyMismatch {
+        campaign: String,
+        expected: String,
+        found: String,
+    },
+}
+
+impl fmt::Display for SpendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpendError::CurrencyMismatch {
+                campaign,
+                expected,
+                found,
+            } => write!(
+                f,
+                "campaign '{}' is tracked in {} but the amount is in {}",
+                campaign, expected, found
+            ),
+        }
+    }
+}
+
+impl Error for SpendError {}
+
+fn check_currency(campaign: &str, spend: &CampaignSpend, found: &str) -> Result<(), SpendError> {
+    match &spend.price_unit {
+        Some(expected) if !expected.eq_ignore_ascii_case(found) => {
+            Err(SpendError::CurrencyMismatch {
+                campaign: campaign.to_string(),
+                expected: expected.clone(),
+                found: found.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Tracks estimated and actual spend per campaign. Each campaign is kept in
+/// the currency of its first estimate; amounts in any other currency are
+/// rejected with `SpendError::CurrencyMismatch` rather than added.
+#[derive(Debug, Default)]
+pub struct SpendTracker {
+    estimates: HashMap<String, (String, CostEstimate)>,
+    campaigns: HashMap<String, CampaignSpend>,
+}
+
+impl SpendTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a sent message against `campaign` with its estimated cost.
+    pub fn record_sent(
+        &mut self,
+        campaign: &str,
+        message_sid: &str,
+        estimate: CostEstimate,
+    ) -> Result<(), SpendError> {
+        let spend = self.campaigns.entry(campaign.to_string()).or_default();
+        check_currency(campaign, spend, &estimate.price_unit)?;
+        spend.messages += 1;
+        spend.estimated_total = spend.estimated_total + estimate.total;
+        spend
+            .price_unit
+            .get_or_insert_with(|| estimate.price_unit.clone());
+        self.estimates
+            .insert(message_sid.to_string(), (campaign.to_string(), estimate));
+        Ok(())
+    }
+
+    /// Applies the finalized price from a fetched message. Returns `None` if
+    /// the message is unknown or its price is not final yet; a price in
+    /// another currency is an error and leaves the message pending.
+    pub fn record_final(
+        &mut self,
+        message: &TwilioMessageResponse,
+    ) -> Result<Option<CostReconciliation>, SpendError> {
+        let Some((campaign, estimate)) = self.estimates.get(&message.sid) else {
+            return Ok(None);
+        };
+        let Some(reconciliation) = reconcile(estimate, message) else {
+            return Ok(None);
+        };
+        let Some(spend) = self.campaigns.get_mut(campaign) else {
+            return Ok(None);
+        };
+        check_currency(campaign, spend, &reconciliation.price_unit)?;
+        spend.actual_total = spend.actual_total + reconciliation.actual;
+        spend.reconciled += 1;
+        self.estimates.remove(&message.sid);
+        Ok(Some(reconciliation))
+    }
+
+    /// SIDs of messages still waiting for a final price.
+    pub fn pending_message_sids(&self) -> Vec<&str> {
+        self.estimates.keys().map(String::as_str).collect()
+    }
+
+    /// Fetches every pending message and applies any finalized prices.
+    pub async fn reconcile_pending(
+        &mut self,
+        client: &TwilioClient,
+    ) -> Result<Vec<CostReconciliation>, Box<dyn Error>> {
+        let sids: Vec<String> = self.estimates.keys().cloned().collect();
+        let mut reconciled = Vec::new();
+        for sid in sids {
+            let message = client.fetch_message(&sid).await?;
+            if let Some(reconciliation) = self.record_final(&message)? {
+                reconciled.push(reconciliation);
+            }
+        }
+        Ok(reconciled)
+    }
+
+    pub fn campaign(&self, campaign: &str) -> Option<&CampaignSpend> {
+        self.campaigns.get(campaign)
+    }
+
+    pub fn campaigns(&self) -> &HashMap<String, CampaignSpend> {
+        &self.campaigns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pricing(iso: &str, prices: &[(&str, &str)]) -> MessagingCountryPricing {
+        MessagingCountryPricing {
+            country: iso.to_string(),
+            iso_country: iso.to_string(),
+            outbound_sms_prices: vec![OutboundSmsPrice {
+                carrier: None,
+                mcc: None,
+                mnc: None,
+                prices: prices
+                    .iter()
+                    .map(|(number_type, price)| NumberTypePrice {
+                        number_type: number_type.to_string(),
+                        base_price: None,
+                        current_price: Some(price.to_string()),
+                    })
+                    .collect(),
+            }],
+            inbound_sms_prices: Vec::new(),
+            price_unit: "USD".to_string(),
+        }
+    }
+
+    #[test]
+    fn estimates_use_the_sender_number_type() {
+        let mut table = PriceTable::new();
+        table.insert(pricing(
+            "US",
+            &[("local", "0.0079"), ("toll free", "0.0083")],
+        ));
+        let to = PhoneNumber::parse("+1 212 555 0100", None).unwrap();
+
+        let estimate = table
+            .estimate_sms(&to, SenderNumberType::TollFree, "hello")
+            .unwrap();
+        assert_eq!(estimate.segments, 1);
+        assert_eq!(estimate.unit_price.to_string(), "0.0083");
+        assert!(table
+            .estimate_sms(&to, SenderNumberType::Mobile, "hello")
+            .is_none());
+    }
+
+    #[test]
+    fn canadian_numbers_use_canadian_prices() {
+        let mut table = PriceTable::new();
+        table.insert(pricing("US", &[("local", "0.0079")]));
+        table.insert(pricing("CA", &[("local", "0.0080")]));
+        let to = PhoneNumber::parse("+1 416 555 0100", None).unwrap();
+
+        let estimate = table
+            .estimate_sms(&to, SenderNumberType::Local, "hello")
+            .unwrap();
+        assert_eq!(estimate.iso_country, "CA");
+        assert_eq!(estimate.unit_price.to_string(), "0.0080");
+    }
+
+    fn finalized(sid: &str, price: &str, price_unit: &str) -> TwilioMessageResponse {
+        serde_json::from_value(serde_json::json!({
+            "sid": sid, "status": "delivered", "to": "+12125550100", "from": "+12125550199",
+            "body": "hello", "account_sid": "AC123", "api_version": "2010-04-01",
+            "num_media": "0", "num_segments": "1", "price": price, "price_unit": price_unit
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn campaign_totals_are_exact() {
+        let mut table = PriceTable::new();
+        table.insert(pricing("US", &[("local", "0.0079")]));
+        let to = PhoneNumber::parse("+1 212 555 0100", None).unwrap();
+        let estimate = table
+            .estimate_sms(&to, SenderNumberType::Local, "hello")
+            .unwrap();
+
+        let mut tracker = SpendTracker::new();
+        for n in 0..10 {
+            tracker
+                .record_sent("launch", &format!("SM{}", n), estimate.clone())
+                .unwrap();
+        }
+        for n in 0..10 {
+            let reconciliation = tracker
+                .record_final(&finalized(&format!("SM{}", n), "-0.0080", "USD"))
+                .unwrap()
+                .unwrap();
+            assert_eq!(reconciliation.difference().to_string(), "0.0001");
+        }
+
+        let spend = tracker.campaign("launch").unwrap();
+        assert_eq!(spend.estimated_total.to_string(), "0.0790");
+        assert_eq!(spend.actual_total.to_string(), "0.0800");
+        assert_eq!(spend.pending(), 0);
+    }
+
+    #[test]
+    fn campaigns_reject_other_currencies() {
+        let mut table = PriceTable::new();
+        table.insert(pricing("US", &[("local", "0.0079")]));
+        let mut gb = pricing("GB", &[("local", "0.0400")]);
+        gb.price_unit = "GBP".to_string();
+        table.insert(gb);
+        let estimate = |number: &str| {
+            let to = PhoneNumber::parse(number, None).unwrap();
+            table
+                .estimate_sms(&to, SenderNumberType::Local, "hello")
+                .unwrap()
+        };
+
+        let mut tracker = SpendTracker::new();
+        tracker
+            .record_sent("launch", "SM1", estimate("+1 212 555 0100"))
+            .unwrap();
+        assert_eq!(
+            tracker.record_sent("launch", "SM2", estimate("+44 7700 900123")),
+            Err(SpendError::CurrencyMismatch {
+                campaign: "launch".to_string(),
+                expected: "USD".to_string(),
+                found: "GBP".to_string(),
+            })
+        );
+        assert_eq!(tracker.campaign("launch").unwrap().messages, 1);
+
+        assert!(tracker
+            .record_final(&finalized("SM1", "-0.0300", "GBP"))
+            .is_err());
+        assert_eq!(tracker.pending_message_sids(), ["SM1"]);
+        assert!(tracker
+            .record_final(&finalized("SM1", "-0.0079", "USD"))
+            .unwrap()
+            .is_some());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::client::{send_empty, send_json, TwilioClient};
+use reqwest::Method;
+use serde::Deserialize;
+use std::error::Error;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct QueueResource {
+    pub sid: String,
+    pub account_sid: String,
+    pub friendly_name: String,
+    pub current_size: u32,
+    pub max_size: u32,
+    /// Average seconds callers currently in the queue have waited.
+    pub average_wait_time: u32,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct QueuePage {
+    queues: Vec<QueueResource>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct QueueMember {
+    pub call_sid: String,
+    pub queue_sid: String,
+    /// 1-based position; 1 is the next caller to be dequeued.
+    pub position: u32,
+    /// Seconds the caller has been waiting.
+    pub wait_time: u32,
+    pub date_enqueued: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct QueueMemberPage {
+    queue_members: Vec<QueueMember>,
+}
+
+/// Member addressed by `dequeue_member` to take whoever is first in line.
+pub const FRONT_OF_QUEUE: &str = "Front";
+
+impl TwilioClient {
+    /// Creates a queue; `max_size` defaults to 100 on Twilio's side.
+    pub async fn create_queue(
+        &self,
+        friendly_name: &str,
+        max_size: Option<u32>,
+    ) -> Result<QueueResource, Box<dyn Error>> {
+        let mut params = vec![("FriendlyName", friendly_name.to_string())];
+        if let Some(max_size) = max_size {
+            params.push(("MaxSize", max_size.to_string()));
+        }
+        send_json(
+            self.request(Method::POST, &self.api_url("Queues.json"))
+                .form(&params),
+        )
+        .await
+    }
+
+    pub async fn list_queues(&self) -> Result<Vec<QueueResource>, Box<dyn Error>> {
+        let page: QueuePage =
+            send_json(self.request(Method::GET, &self.api_url("Queues.json"))).await?;
+        Ok(page.queues)
+    }
+
+    /// Fetches a queue, including its current size and average wait time.
+    pub async fn fetch_queue(&self, queue_sid: &str) -> Result<QueueResource, Box<dyn Error>> {
+        let url = self.api_url(&format!("Queues/{}.json", queue_sid));
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    /// Average wait in seconds of the callers currently in the queue.
+    pub async fn queue_average_wait_time(&self, queue_sid: &str) -> Result<u32, Box<dyn Error>> {
+        Ok(self.fetch_queue(queue_sid).await?.average_wait_time)
+    }
+
+    pub async fn update_queue_max_size(
+        &self,
+        queue_sid: &str,
+        max_size: u32,
+    ) -> Result<QueueResource, Box<dyn Error>> {
+        let url = self.api_url(&format!("Queues/{}.json", queue_sid));
+        let params = [("MaxSize", max_size.to_string())];
+        send_json(self.request(Method::POST, &url).form(&params)).await
+    }
+
+    /// Deletes a queue; Twilio refuses while callers are still waiting in it.
+    pub async fn delete_queue(&self, queue_sid: &str) -> Result<(), Box<dyn Error>> {
+        let url = self.api_url(&format!("Queues/{}.json", queue_sid));
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+
+    pub async fn list_queue_members(
+        &self,
+        queue_sid: &str,
+    ) -> Result<Vec<QueueMember>, Box<dyn Error>> {
+        let url = self.api_url(&format!("Queues/{}/Members.json", queue_sid));
+        let page: QueueMemberPage = send_json(self.request(Method::GET, &url)).await?;
+        Ok(page.queue_members)
+    }
+
+    /// Takes a caller out of the queue and executes the TwiML at `url` for
+    /// them. Pass `FRONT_OF_QUEUE` or a specific call SID as `member`.
+    pub async fn dequeue_member(
+        &self,
+        queue_sid: &str,
+        member: &str,
+        url: &str,
+        method: Option<&str>,
+    ) -> Result<QueueMember, Box<dyn Error>> {
+        let mut params = vec![("Url", url.to_string())];
+        if let Some(method) = method {
+            params.push(("Method", method.to_string()));
+        }
+        let endpoint = self.api_url(&format!("Queues/{}/Members/{}.json", queue_sid, member));
+        send_json(self.request(Method::POST, &endpoint).form(&params)).await
+    }
+
+    /// Dequeues whoever has waited longest.
+    pub async fn dequeue_front(
+        &self,
+        queue_sid: &str,
+        url: &str,
+    ) -> Result<QueueMember, Box<dyn Error>> {
+        self.dequeue_member(queue_sid, FRONT_OF_QUEUE, url, None)
+            .await
+    }
+}
@@ -0,0 +1,411 @@
+use crate::client::{check_status, send_empty, send_json, TwilioClient};
+use reqwest::{Method, Response};
+use serde::Deserialize;
+use std::error::Error;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum RecordingStatus {
+    InProgress,
+    Paused,
+    Stopped,
+    Processing,
+    Completed,
+    Absent,
+    Deleted,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RecordingResource {
+    pub sid: String,
+    pub account_sid: String,
+    pub call_sid: Option<String>,
+    pub conference_sid: Option<String>,
+    pub status: RecordingStatus,
+    /// Seconds; `-1` while the recording is still in progress.
+    pub duration: Option<String>,
+    pub channels: Option<u32>,
+    /// What started the recording, e.g. `StartCallRecordingAPI` or `RecordVerb`.
+    pub source: Option<String>,
+    pub track: Option<String>,
+    pub start_time: Option<String>,
+    pub price: Option<String>,
+    pub price_unit: Option<String>,
+    pub error_code: Option<u32>,
+    pub media_url: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RecordingPage {
+    recordings: Vec<RecordingResource>,
+}
+
+/// Filters for `TwilioClient::list_recordings`; unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingFilter {
+    pub call_sid: Option<String>,
+    pub conference_sid: Option<String>,
+    /// `YYYY-MM-DD`; only recordings created on this day.
+    pub date_created: Option<String>,
+    pub date_created_before: Option<String>,
+    pub date_created_after: Option<String>,
+    pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingFormat {
+    Wav,
+    Mp3,
+}
+
+impl RecordingFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RecordingFormat::Wav => "wav",
+            RecordingFormat::Mp3 => "mp3",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            RecordingFormat::Wav => "audio/x-wav",
+            RecordingFormat::Mp3 => "audio/mpeg",
+        }
+    }
+}
+
+/// Audio of a recording, read chunk by chunk so large files never need to
+/// be held in memory.
+pub struct RecordingDownload {
+    response: Response,
+}
+
+impl RecordingDownload {
+    /// Size in bytes, when Twilio reports it.
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// Next chunk of audio, or `None` once the download is complete.
+    pub async fn chunk(&mut self) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+        Ok(self.response.chunk().await?.map(|bytes| bytes.to_vec()))
+    }
+
+    /// Streams the remaining audio into `writer`, returning the bytes written.
+    pub async fn write_to<W: AsyncWrite + Unpin>(
+        mut self,
+        writer: &mut W,
+    ) -> Result<u64, Box<dyn Error>> {
+        let mut written = 0;
+        while let Some(bytes) = self.response.chunk().await? {
+            writer.write_all(&bytes).await?;
+            written += bytes.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+}
+
+/// Which side(s) of a live call to record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingTrack {
+    Inbound,
+    Outbound,
+    Both,
+}
+
+impl RecordingTrack {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingTrack::Inbound => "inbound",
+            RecordingTrack::Outbound => "outbound",
+            RecordingTrack::Both => "both",
+        }
+    }
+}
+
+/// Recording progress events that trigger the `RecordingStatusCallback`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingStatusEvent {
+    InProgress,
+    Completed,
+    Absent,
+}
+
+impl RecordingStatusEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingStatusEvent::InProgress => "in-progress",
+            RecordingStatusEvent::Completed => "completed",
+            RecordingStatusEvent::Absent => "absent",
+        }
+    }
+}
+
+/// Parameters for `TwilioClient::start_call_recording`.
+#[derive(Debug, Clone, Default)]
+pub struct StartRecordingRequest {
+    pub recording_status_callback: Option<String>,
+    pub recording_status_callback_method: Option<String>,
+    /// Empty means Twilio's default (`completed` only).
+    pub recording_status_callback_events: Vec<RecordingStatusEvent>,
+    /// Record each party on its own channel instead of mixing them.
+    pub dual_channel: bool,
+    pub track: Option<RecordingTrack>,
+    /// Remove leading and trailing silence.
+    pub trim_silence: bool,
+}
+
+/// What a paused recording contains for the paused period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseBehavior {
+    /// Leave the paused period out of the recording.
+    Skip,
+    /// Fill the paused period with silence.
+    Silence,
+}
+
+impl PauseBehavior {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PauseBehavior::Skip => "skip",
+            PauseBehavior::Silence => "silence",
+        }
+    }
+}
+
+/// Refers to whichever recording is currently active on a call, for use in
+/// place of a recording SID.
+pub const CURRENT_RECORDING: &str = "Twilio.CURRENT";
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum TranscriptionStatus {
+    InProgress,
+    Completed,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TranscriptionResource {
+    pub sid: String,
+    pub account_sid: String,
+    pub recording_sid: Option<String>,
+    pub status: TranscriptionStatus,
+    pub transcription_text: Option<String>,
+    pub duration: Option<String>,
+    pub price: Option<String>,
+    pub price_unit: Option<String>,
+    #[serde(rename = "type")]
+    pub transcription_type: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct TranscriptionPage {
+    transcriptions: Vec<TranscriptionResource>,
+}
+
+impl TwilioClient {
+    pub async fn list_recordings(
+        &self,
+        filter: &RecordingFilter,
+    ) -> Result<Vec<RecordingResource>, Box<dyn Error>> {
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(call_sid) = &filter.call_sid {
+            query.push(("CallSid", call_sid.clone()));
+        }
+        if let Some(conference_sid) = &filter.conference_sid {
+            query.push(("ConferenceSid", conference_sid.clone()));
+        }
+        if let Some(date) = &filter.date_created {
+            query.push(("DateCreated", date.clone()));
+        }
+        if let Some(date) = &filter.date_created_before {
+            query.push(("DateCreated<", date.clone()));
+        }
+        if let Some(date) = &filter.date_created_after {
+            query.push(("DateCreated>", date.clone()));
+        }
+        if let Some(page_size) = filter.page_size {
+            query.push(("PageSize", page_size.to_string()));
+        }
+
+        let page: RecordingPage = send_json(
+            self.request(Method::GET, &self.api_url("Recordings.json"))
+                .query(&query),
+        )
+        .await?;
+        Ok(page.recordings)
+    }
+
+    pub async fn list_call_recordings(
+        &self,
+        call_sid: &str,
+    ) -> Result<Vec<RecordingResource>, Box<dyn Error>> {
+        let url = self.api_url(&format!("Calls/{}/Recordings.json", call_sid));
+        let page: RecordingPage = send_json(self.request(Method::GET, &url)).await?;
+        Ok(page.recordings)
+    }
+
+    pub async fn fetch_recording(
+        &self,
+        recording_sid: &str,
+    ) -> Result<RecordingResource, Box<dyn Error>> {
+        let url = self.api_url(&format!("Recordings/{}.json", recording_sid));
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    /// Permanently deletes the recording's audio and metadata.
+    pub async fn delete_recording(&self, recording_sid: &str) -> Result<(), Box<dyn Error>> {
+        let url = self.api_url(&format!("Recordings/{}.json", recording_sid));
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+
+    /// Opens an authenticated download of the recording's audio.
+    pub async fn download_recording(
+        &self,
+        recording_sid: &str,
+        format: RecordingFormat,
+    ) -> Result<RecordingDownload, Box<dyn Error>> {
+        let url = self.api_url(&format!(
+            "Recordings/{}.{}",
+            recording_sid,
+            format.extension()
+        ));
+        let response = check_status(self.request(Method::GET, &url).send().await?).await?;
+        Ok(RecordingDownload { response })
+    }
+
+    /// Starts recording a call that is already in progress.
+    pub async fn start_call_recording(
+        &self,
+        call_sid: &str,
+        request: &StartRecordingRequest,
+    ) -> Result<RecordingResource, Box<dyn Error>> {
+        let mut params: Vec<(&str, String)> = Vec::new();
+        if let Some(callback) = &request.recording_status_callback {
+            params.push(("RecordingStatusCallback", callback.clone()));
+        }
+        if !request.recording_status_callback_events.is_empty() {
+            let events: Vec<&str> = request
+                .recording_status_callback_events
+                .iter()
+                .map(RecordingStatusEvent::as_str)
+                .collect();
+            params.push(("RecordingStatusCallbackEvent", events.join(" ")));
+        }
+        if let Some(method) = &request.recording_status_callback_method {
+            params.push(("RecordingStatusCallbackMethod", method.clone()));
+        }
+        if request.dual_channel {
+            params.push(("RecordingChannels", "dual".to_string()));
+        }
+        if let Some(track) = request.track {
+            params.push(("RecordingTrack", track.as_str().to_string()));
+        }
+        if request.trim_silence {
+            params.push(("Trim", "trim-silence".to_string()));
+        }
+
+        let url = self.api_url(&format!("Calls/{}/Recordings.json", call_sid));
+        send_json(self.request(Method::POST, &url).form(&params)).await
+    }
+
+    /// Pauses a live recording; pass `CURRENT_RECORDING` to target the
+    /// active one without knowing its SID.
+    pub async fn pause_call_recording(
+        &self,
+        call_sid: &str,
+        recording_sid: &str,
+        behavior: PauseBehavior,
+    ) -> Result<RecordingResource, Box<dyn Error>> {
+        let params = [
+            ("Status", "paused".to_string()),
+            ("PauseBehavior", behavior.as_str().to_string()),
+        ];
+        self.update_call_recording(call_sid, recording_sid, &params)
+            .await
+    }
+
+    pub async fn resume_call_recording(
+        &self,
+        call_sid: &str,
+        recording_sid: &str,
+    ) -> Result<RecordingResource, Box<dyn Error>> {
+        self.update_call_recording(
+            call_sid,
+            recording_sid,
+            &[("Status", "in-progress".to_string())],
+        )
+        .await
+    }
+
+    pub async fn stop_call_recording(
+        &self,
+        call_sid: &str,
+        recording_sid: &str,
+    ) -> Result<RecordingResource, Box<dyn Error>> {
+        self.update_call_recording(
+            call_sid,
+            recording_sid,
+            &[("Status", "stopped".to_string())],
+        )
+        .await
+    }
+
+    async fn update_call_recording(
+        &self,
+        call_sid: &str,
+        recording_sid: &str,
+        params: &[(&str, String)],
+    ) -> Result<RecordingResource, Box<dyn Error>> {
+        let url = self.api_url(&format!(
+            "Calls/{}/Recordings/{}.json",
+            call_sid, recording_sid
+        ));
+        send_json(self.request(Method::POST, &url).form(params)).await
+    }
+
+    pub async fn list_transcriptions(&self) -> Result<Vec<TranscriptionResource>, Box<dyn Error>> {
+        let page: TranscriptionPage =
+            send_json(self.request(Method::GET, &self.api_url("Transcriptions.json"))).await?;
+        Ok(page.transcriptions)
+    }
+
+    pub async fn list_recording_transcriptions(
+        &self,
+        recording_sid: &str,
+    ) -> Result<Vec<TranscriptionResource>, Box<dyn Error>> {
+        let url = self.api_url(&format!("Recordings/{}/Transcriptions.json", recording_sid));
+        let page: TranscriptionPage = send_json(self.request(Method::GET, &url)).await?;
+        Ok(page.transcriptions)
+    }
+
+    pub async fn fetch_transcription(
+        &self,
+        transcription_sid: &str,
+    ) -> Result<TranscriptionResource, Box<dyn Error>> {
+        let url = self.api_url(&format!("Transcriptions/{}.json", transcription_sid));
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    pub async fn delete_transcription(
+        &self,
+        transcription_sid: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let url = self.api_url(&format!("Transcriptions/{}.json", transcription_sid));
+        send_empty(self.request(Method::DELETE, &url)).await
+    }
+}
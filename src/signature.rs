@@ -3,12 +3,18 @@ use base64::Engine;
 use hmac::{Hmac, Mac};
 use sha1::Sha1;
 
+/// Always `false` for an empty `auth_token`, since anyone can compute a
+/// signature with an empty key.
 pub fn validate_twilio_signature(
     url: &str,
     form_params: &std::collections::HashMap<String, String>,
     signature_header: &str,
     auth_token: &str,
 ) -> bool {
+    if auth_token.is_empty() {
+        return false;
+    }
+
     let mut keys: Vec<&String> = form_params.keys().collect();
     keys.sort();
 
@@ -26,3 +32,65 @@ pub fn validate_twilio_signature(
 
     computed_signature == signature_header
 }
+
+/// Accepts a signature made with any of `auth_tokens`, for use while an auth
+/// token rotation is being rolled out.
+pub fn validate_twilio_signature_any(
+    url: &str,
+    form_params: &std::collections::HashMap<String, String>,
+    signature_header: &str,
+    auth_tokens: &[&str],
+) -> bool {
+    auth_tokens
+        .iter()
+        .any(|token| validate_twilio_signature(url, form_params, signature_header, token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn sign(url: &str, params: &HashMap<String, String>, token: &str) -> String {
+        let mut keys: Vec<&String> = params.keys().collect();
+        keys.sort();
+        let mut data = url.to_string();
+        for key in keys {
+            data.push_str(key);
+            data.push_str(&params[key]);
+        }
+        let mut mac = Hmac::<Sha1>::new_from_slice(token.as_bytes()).unwrap();
+        mac.update(data.as_bytes());
+        STANDARD.encode(mac.finalize().into_bytes())
+    }
+
+    #[test]
+    fn accepts_signature_from_any_configured_token() {
+        let url = "https://example.com/twilio/whatsapp";
+        let params = HashMap::from([("Body".to_string(), "hi".to_string())]);
+        let signature = sign(url, &params, "secondary");
+        assert!(validate_twilio_signature_any(
+            url,
+            &params,
+            &signature,
+            &["primary", "secondary"]
+        ));
+        assert!(!validate_twilio_signature(
+            url, &params, &signature, "primary"
+        ));
+    }
+
+    #[test]
+    fn rejects_signatures_made_with_an_empty_token() {
+        let url = "https://example.com/twilio/whatsapp";
+        let params = HashMap::from([("Body".to_string(), "hi".to_string())]);
+        let forged = sign(url, &params, "");
+        assert!(!validate_twilio_signature(url, &params, &forged, ""));
+        assert!(!validate_twilio_signature_any(
+            url,
+            &params,
+            &forged,
+            &["", ""]
+        ));
+    }
+}
@@ -1,34 +1,80 @@
 use crate::client::TwilioClient;
-use reqwest::Client;
-use serde::Deserialize;
-
-#[derive(Deserialize, Debug)]
-pub struct TwilioMessageResponse {
-    pub sid: String,
-    pub status: String,
-    pub to: String,
-    pub from: String,
-    pub body: String,
+use crate::encoding::{analyze_sms, smart_encode, SmsEncoding};
+use crate::messaging::{Address, OutboundMessage};
+use std::fmt;
+
+pub use crate::messaging::TwilioMessageResponse;
+
+#[derive(Debug, Clone, Default)]
+pub struct SmsOptions {
+    /// Replace Unicode look-alikes with GSM-7 characters before sending.
+    pub smart_encoding: bool,
+    /// Refuse to send bodies that would be billed as more segments than this.
+    pub max_segments: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SmsError {
+    TooManySegments {
+        segments: usize,
+        max_segments: usize,
+        encoding: SmsEncoding,
+    },
 }
 
+impl fmt::Display for SmsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SmsError::TooManySegments {
+                segments,
+                max_segments,
+                encoding,
+            } => write!(
+                f,
+                "SMS body needs {} {} segments, more than the allowed {}",
+                segments, encoding, max_segments
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SmsError {}
+
 impl TwilioClient {
     pub async fn send_sms(
         &self,
         to: &str,
         body: &str,
     ) -> Result<TwilioMessageResponse, Box<dyn std::error::Error>> {
-        let client = Client::new();
-        let params = [("To", to), ("From", &self.from_phone), ("Body", body)];
-
-        let res = client
-            .post(&self.base_url())
-            .basic_auth(&self.account_sid, Some(&self.auth_token))
-            .form(&params)
-            .send()
-            .await?
-            .json::<TwilioMessageResponse>()
-            .await?;
-
-        Ok(res)
+        self.send_sms_with_options(to, body, &SmsOptions::default())
+            .await
+    }
+
+    pub async fn send_sms_with_options(
+        &self,
+        to: &str,
+        body: &str,
+        options: &SmsOptions,
+    ) -> Result<TwilioMessageResponse, Box<dyn std::error::Error>> {
+        let body = if options.smart_encoding {
+            smart_encode(body)
+        } else {
+            body.to_string()
+        };
+
+        if let Some(max_segments) = options.max_segments {
+            let info = analyze_sms(&body);
+            if info.segments > max_segments {
+                return Err(SmsError::TooManySegments {
+                    segments: info.segments,
+                    max_segments,
+                    encoding: info.encoding,
+                }
+                .into());
+            }
+        }
+
+        let to = Address::Sms(self.parse_number(to)?);
+        self.send_message(&to, &OutboundMessage::text(&body)).await
     }
 }
@@ -0,0 +1,1554 @@
+use std::fmt;
+
+/// Generic TwiML element, the intermediate form every typed verb renders to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwimlElement {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<TwimlNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TwimlNode {
+    Element(TwimlElement),
+    Text(String),
+}
+
+impl TwimlElement {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            attributes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Concatenated text content of the direct children.
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                TwimlNode::Text(text) => Some(text.as_str()),
+                TwimlNode::Element(_) => None,
+            })
+            .collect()
+    }
+
+    pub fn child_elements(&self) -> impl Iterator<Item = &TwimlElement> {
+        self.children.iter().filter_map(|child| match child {
+            TwimlNode::Element(element) => Some(element),
+            TwimlNode::Text(_) => None,
+        })
+    }
+
+    fn attr(mut self, name: &str, value: Option<impl ToString>) -> Self {
+        if let Some(value) = value {
+            self.attributes.push((name.to_string(), value.to_string()));
+        }
+        self
+    }
+
+    fn list_attr(self, name: &str, values: &[String], separator: &str) -> Self {
+        let value = (!values.is_empty()).then(|| values.join(separator));
+        self.attr(name, value)
+    }
+
+    fn text_child(mut self, text: &str) -> Self {
+        self.children.push(TwimlNode::Text(text.to_string()));
+        self
+    }
+
+    fn child(mut self, element: TwimlElement) -> Self {
+        self.children.push(TwimlNode::Element(element));
+        self
+    }
+
+    fn write(&self, out: &mut String) {
+        out.push('<');
+        out.push_str(&self.name);
+        for (key, value) in &self.attributes {
+            out.push(' ');
+            out.push_str(key);
+            out.push_str("=\"");
+            out.push_str(&escape_xml(value));
+            out.push('"');
+        }
+        if self.children.is_empty() {
+            out.push_str("/>");
+            return;
+        }
+        out.push('>');
+        for child in &self.children {
+            match child {
+                TwimlNode::Element(element) => element.write(out),
+                TwimlNode::Text(text) => out.push_str(&escape_xml(text)),
+            }
+        }
+        out.push_str("</");
+        out.push_str(&self.name);
+        out.push('>');
+    }
+
+    /// Serializes the element without an XML declaration.
+    pub fn to_xml(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out);
+        out
+    }
+}
+
+pub fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub(crate) const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>"#;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TwimlError {
+    /// A verb follows one that ends or transfers control of the call.
+    UnreachableVerb {
+        verb: &'static str,
+        after: &'static str,
+    },
+    /// A verb or noun is missing required content.
+    Empty(&'static str),
+    InvalidNesting {
+        parent: String,
+        child: String,
+    },
+    InvalidAttribute {
+        element: &'static str,
+        attribute: &'static str,
+        reason: String,
+    },
+    /// The document could not be read (used by the parser).
+    Malformed(String),
+}
+
+impl fmt::Display for TwimlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TwimlError::UnreachableVerb { verb, after } => {
+                write!(f, "<{}> can never run because it follows <{}>", verb, after)
+            }
+            TwimlError::Empty(element) => write!(f, "<{}> must not be empty", element),
+            TwimlError::InvalidNesting { parent, child } => {
+                write!(f, "<{}> cannot be nested inside <{}>", child, parent)
+            }
+            TwimlError::InvalidAttribute {
+                element,
+                attribute,
+                reason,
+            } => write!(f, "invalid {} on <{}>: {}", attribute, element, reason),
+            TwimlError::Malformed(reason) => write!(f, "malformed TwiML: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for TwimlError {}
+
+/// SSML markup allowed inside `<Say>` for Amazon Polly and Google voices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SsmlNode {
+    Text(String),
+    Break {
+        strength: Option<String>,
+        time: Option<String>,
+    },
+    Emphasis {
+        level: Option<String>,
+        children: Vec<SsmlNode>,
+    },
+    Prosody {
+        rate: Option<String>,
+        pitch: Option<String>,
+        volume: Option<String>,
+        children: Vec<SsmlNode>,
+    },
+    SayAs {
+        interpret_as: String,
+        format: Option<String>,
+        text: String,
+    },
+    Phoneme {
+        alphabet: Option<String>,
+        ph: String,
+        text: String,
+    },
+    Sub {
+        alias: String,
+        text: String,
+    },
+    Lang {
+        lang: String,
+        children: Vec<SsmlNode>,
+    },
+    Paragraph(Vec<SsmlNode>),
+    Sentence(Vec<SsmlNode>),
+}
+
+impl SsmlNode {
+    fn to_node(&self) -> TwimlNode {
+        let element = match self {
+            SsmlNode::Text(text) => return TwimlNode::Text(text.clone()),
+            SsmlNode::Break { strength, time } => TwimlElement::new("break")
+                .attr("strength", strength.as_ref())
+                .attr("time", time.as_ref()),
+            SsmlNode::Emphasis { level, children } => ssml_children(
+                TwimlElement::new("emphasis").attr("level", level.as_ref()),
+                children,
+            ),
+            SsmlNode::Prosody {
+                rate,
+                pitch,
+                volume,
+                children,
+            } => ssml_children(
+                TwimlElement::new("prosody")
+                    .attr("rate", rate.as_ref())
+                    .attr("pitch", pitch.as_ref())
+                    .attr("volume", volume.as_ref()),
+                children,
+            ),
+            SsmlNode::SayAs {
+                interpret_as,
+                format,
+                text,
+            } => TwimlElement::new("say-as")
+                .attr("interpret-as", Some(interpret_as))
+                .attr("format", format.as_ref())
+                .text_child(text),
+            SsmlNode::Phoneme { alphabet, ph, text } => TwimlElement::new("phoneme")
+                .attr("alphabet", alphabet.as_ref())
+                .attr("ph", Some(ph))
+                .text_child(text),
+            SsmlNode::Sub { alias, text } => TwimlElement::new("sub")
+                .attr("alias", Some(alias))
+                .text_child(text),
+            SsmlNode::Lang { lang, children } => ssml_children(
+                TwimlElement::new("lang").attr("xml:lang", Some(lang)),
+                children,
+            ),
+            SsmlNode::Paragraph(children) => ssml_children(TwimlElement::new("p"), children),
+            SsmlNode::Sentence(children) => ssml_children(TwimlElement::new("s"), children),
+        };
+        TwimlNode::Element(element)
+    }
+}
+
+fn ssml_children(mut element: TwimlElement, children: &[SsmlNode]) -> TwimlElement {
+    element
+        .children
+        .extend(children.iter().map(SsmlNode::to_node));
+    element
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Say {
+    pub content: Vec<SsmlNode>,
+    pub voice: Option<String>,
+    pub language: Option<String>,
+    pub loop_count: Option<u32>,
+}
+
+impl Say {
+    pub fn new(text: &str) -> Self {
+        Self {
+            content: vec![SsmlNode::Text(text.to_string())],
+            ..Default::default()
+        }
+    }
+
+    pub fn ssml(content: Vec<SsmlNode>) -> Self {
+        Self {
+            content,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_voice(mut self, voice: &str) -> Self {
+        self.voice = Some(voice.to_string());
+        self
+    }
+
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        ssml_children(
+            TwimlElement::new("Say")
+                .attr("voice", self.voice.as_ref())
+                .attr("language", self.language.as_ref())
+                .attr("loop", self.loop_count),
+            &self.content,
+        )
+    }
+
+    fn validate(&self) -> Result<(), TwimlError> {
+        let blank = self.content.iter().all(|node| match node {
+            SsmlNode::Text(text) => text.trim().is_empty(),
+            _ => false,
+        });
+        if blank {
+            return Err(TwimlError::Empty("Say"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Play {
+    pub url: Option<String>,
+    pub loop_count: Option<u32>,
+    /// DTMF tones to play instead of audio (e.g. `"wwww1234"`).
+    pub digits: Option<String>,
+}
+
+impl Play {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: Some(url.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn digits(digits: &str) -> Self {
+        Self {
+            digits: Some(digits.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        let element = TwimlElement::new("Play")
+            .attr("loop", self.loop_count)
+            .attr("digits", self.digits.as_ref());
+        match &self.url {
+            Some(url) => element.text_child(url),
+            None => element,
+        }
+    }
+
+    fn validate(&self) -> Result<(), TwimlError> {
+        if self.url.as_deref().unwrap_or("").is_empty() && self.digits.is_none() {
+            return Err(TwimlError::Empty("Play"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Pause {
+    pub length: Option<u32>,
+}
+
+impl Pause {
+    pub fn new(length: u32) -> Self {
+        Self {
+            length: Some(length),
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        TwimlElement::new("Pause").attr("length", self.length)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatherInput {
+    Dtmf,
+    Speech,
+}
+
+impl GatherInput {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GatherInput::Dtmf => "dtmf",
+            GatherInput::Speech => "speech",
+        }
+    }
+}
+
+/// Verbs that may be nested inside `<Gather>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GatherChild {
+    Say(Say),
+    Play(Play),
+    Pause(Pause),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Gather {
+    /// Empty means Twilio's default (`dtmf`).
+    pub input: Vec<GatherInput>,
+    pub action: Option<String>,
+    pub method: Option<String>,
+    pub timeout: Option<u32>,
+    /// Seconds, or `"auto"`.
+    pub speech_timeout: Option<String>,
+    pub num_digits: Option<u32>,
+    pub finish_on_key: Option<String>,
+    pub hints: Vec<String>,
+    pub language: Option<String>,
+    pub speech_model: Option<String>,
+    pub enhanced: Option<bool>,
+    pub profanity_filter: Option<bool>,
+    pub partial_result_callback: Option<String>,
+    pub action_on_empty_result: Option<bool>,
+    pub children: Vec<GatherChild>,
+}
+
+impl Gather {
+    pub fn new(action: &str) -> Self {
+        Self {
+            action: Some(action.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn say(mut self, say: Say) -> Self {
+        self.children.push(GatherChild::Say(say));
+        self
+    }
+
+    pub fn play(mut self, play: Play) -> Self {
+        self.children.push(GatherChild::Play(play));
+        self
+    }
+
+    pub fn pause(mut self, pause: Pause) -> Self {
+        self.children.push(GatherChild::Pause(pause));
+        self
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        let input: Vec<String> = self.input.iter().map(|i| i.as_str().to_string()).collect();
+        let mut element = TwimlElement::new("Gather")
+            .list_attr("input", &input, " ")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("timeout", self.timeout)
+            .attr("speechTimeout", self.speech_timeout.as_ref())
+            .attr("numDigits", self.num_digits)
+            .attr("finishOnKey", self.finish_on_key.as_ref())
+            .list_attr("hints", &self.hints, ",")
+            .attr("language", self.language.as_ref())
+            .attr("speechModel", self.speech_model.as_ref())
+            .attr("enhanced", self.enhanced)
+            .attr("profanityFilter", self.profanity_filter)
+            .attr(
+                "partialResultCallback",
+                self.partial_result_callback.as_ref(),
+            )
+            .attr("actionOnEmptyResult", self.action_on_empty_result);
+        for child in &self.children {
+            element = element.child(match child {
+                GatherChild::Say(say) => say.to_element(),
+                GatherChild::Play(play) => play.to_element(),
+                GatherChild::Pause(pause) => pause.to_element(),
+            });
+        }
+        element
+    }
+
+    fn validate(&self) -> Result<(), TwimlError> {
+        if self.num_digits == Some(0) {
+            return Err(TwimlError::InvalidAttribute {
+                element: "Gather",
+                attribute: "numDigits",
+                reason: "must be at least 1".to_string(),
+            });
+        }
+        for child in &self.children {
+            match child {
+                GatherChild::Say(say) => say.validate()?,
+                GatherChild::Play(play) => play.validate()?,
+                GatherChild::Pause(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Custom parameter passed to a `<Client>` or `<Stream>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: String,
+    pub value: String,
+}
+
+impl Parameter {
+    pub fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        TwimlElement::new("Parameter")
+            .attr("name", Some(&self.name))
+            .attr("value", Some(&self.value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Number {
+    pub number: String,
+    pub send_digits: Option<String>,
+    /// TwiML run for the called party before the calls are bridged.
+    pub url: Option<String>,
+    pub method: Option<String>,
+    pub status_callback: Option<String>,
+    pub status_callback_event: Vec<String>,
+}
+
+impl Number {
+    pub fn new(number: &str) -> Self {
+        Self {
+            number: number.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Client {
+    pub identity: String,
+    pub url: Option<String>,
+    pub method: Option<String>,
+    pub parameters: Vec<Parameter>,
+}
+
+impl Client {
+    pub fn new(identity: &str) -> Self {
+        Self {
+            identity: identity.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sip {
+    pub uri: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub url: Option<String>,
+    pub method: Option<String>,
+}
+
+impl Sip {
+    pub fn new(uri: &str) -> Self {
+        Self {
+            uri: uri.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Conference {
+    pub name: String,
+    pub muted: Option<bool>,
+    /// `true`, `false`, `onEnter` or `onExit`.
+    pub beep: Option<String>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    pub wait_url: Option<String>,
+    pub wait_method: Option<String>,
+    pub max_participants: Option<u32>,
+    pub record: Option<String>,
+    pub status_callback: Option<String>,
+    pub status_callback_event: Vec<String>,
+    /// Call SID of the participant this one coaches.
+    pub coach: Option<String>,
+    pub participant_label: Option<String>,
+}
+
+impl Conference {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Queue {
+    pub name: String,
+    /// TwiML played to the dequeued caller before bridging.
+    pub url: Option<String>,
+    pub method: Option<String>,
+}
+
+impl Queue {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialNoun {
+    Number(Number),
+    Client(Client),
+    Sip(Sip),
+    Conference(Conference),
+    Queue(Queue),
+}
+
+impl DialNoun {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DialNoun::Number(_) => "Number",
+            DialNoun::Client(_) => "Client",
+            DialNoun::Sip(_) => "Sip",
+            DialNoun::Conference(_) => "Conference",
+            DialNoun::Queue(_) => "Queue",
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        match self {
+            DialNoun::Number(number) => TwimlElement::new("Number")
+                .attr("sendDigits", number.send_digits.as_ref())
+                .attr("url", number.url.as_ref())
+                .attr("method", number.method.as_ref())
+                .attr("statusCallback", number.status_callback.as_ref())
+                .list_attr("statusCallbackEvent", &number.status_callback_event, " ")
+                .text_child(&number.number),
+            DialNoun::Client(client) => {
+                let element = TwimlElement::new("Client")
+                    .attr("url", client.url.as_ref())
+                    .attr("method", client.method.as_ref());
+                if client.parameters.is_empty() {
+                    return element.text_child(&client.identity);
+                }
+                let mut element =
+                    element.child(TwimlElement::new("Identity").text_child(&client.identity));
+                for parameter in &client.parameters {
+                    element = element.child(parameter.to_element());
+                }
+                element
+            }
+            DialNoun::Sip(sip) => TwimlElement::new("Sip")
+                .attr("username", sip.username.as_ref())
+                .attr("password", sip.password.as_ref())
+                .attr("url", sip.url.as_ref())
+                .attr("method", sip.method.as_ref())
+                .text_child(&sip.uri),
+            DialNoun::Conference(conference) => TwimlElement::new("Conference")
+                .attr("muted", conference.muted)
+                .attr("beep", conference.beep.as_ref())
+                .attr(
+                    "startConferenceOnEnter",
+                    conference.start_conference_on_enter,
+                )
+                .attr("endConferenceOnExit", conference.end_conference_on_exit)
+                .attr("waitUrl", conference.wait_url.as_ref())
+                .attr("waitMethod", conference.wait_method.as_ref())
+                .attr("maxParticipants", conference.max_participants)
+                .attr("record", conference.record.as_ref())
+                .attr("statusCallback", conference.status_callback.as_ref())
+                .list_attr(
+                    "statusCallbackEvent",
+                    &conference.status_callback_event,
+                    " ",
+                )
+                .attr("coach", conference.coach.as_ref())
+                .attr("participantLabel", conference.participant_label.as_ref())
+                .text_child(&conference.name),
+            DialNoun::Queue(queue) => TwimlElement::new("Queue")
+                .attr("url", queue.url.as_ref())
+                .attr("method", queue.method.as_ref())
+                .text_child(&queue.name),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            DialNoun::Number(number) => number.number.trim().is_empty(),
+            DialNoun::Client(client) => client.identity.trim().is_empty(),
+            DialNoun::Sip(sip) => sip.uri.trim().is_empty(),
+            DialNoun::Conference(conference) => conference.name.trim().is_empty(),
+            DialNoun::Queue(queue) => queue.name.trim().is_empty(),
+        }
+    }
+}
+
+/// Twilio allows at most this many nouns in one `<Dial>`.
+pub const MAX_DIAL_NOUNS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Dial {
+    pub nouns: Vec<DialNoun>,
+    pub action: Option<String>,
+    pub method: Option<String>,
+    pub timeout: Option<u32>,
+    pub time_limit: Option<u32>,
+    pub caller_id: Option<String>,
+    /// `do-not-record`, `record-from-answer`, `record-from-ringing`, …
+    pub record: Option<String>,
+    pub recording_status_callback: Option<String>,
+    pub hangup_on_star: Option<bool>,
+    pub answer_on_bridge: Option<bool>,
+    pub ring_tone: Option<String>,
+}
+
+impl Dial {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn number(number: &str) -> Self {
+        Self::new().noun(DialNoun::Number(Number::new(number)))
+    }
+
+    pub fn noun(mut self, noun: DialNoun) -> Self {
+        self.nouns.push(noun);
+        self
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        let mut element = TwimlElement::new("Dial")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("timeout", self.timeout)
+            .attr("timeLimit", self.time_limit)
+            .attr("callerId", self.caller_id.as_ref())
+            .attr("record", self.record.as_ref())
+            .attr(
+                "recordingStatusCallback",
+                self.recording_status_callback.as_ref(),
+            )
+            .attr("hangupOnStar", self.hangup_on_star)
+            .attr("answerOnBridge", self.answer_on_bridge)
+            .attr("ringTone", self.ring_tone.as_ref());
+        for noun in &self.nouns {
+            element = element.child(noun.to_element());
+        }
+        element
+    }
+
+    fn validate(&self) -> Result<(), TwimlError> {
+        if self.nouns.is_empty() || self.nouns.iter().any(DialNoun::is_empty) {
+            return Err(TwimlError::Empty("Dial"));
+        }
+        if self.nouns.len() > MAX_DIAL_NOUNS {
+            return Err(TwimlError::InvalidNesting {
+                parent: "Dial".to_string(),
+                child: format!("more than {} nouns", MAX_DIAL_NOUNS),
+            });
+        }
+        // Conferences and queues take over the call leg, so they cannot be
+        // dialled alongside anything else.
+        if self.nouns.len() > 1 {
+            if let Some(exclusive) = self
+                .nouns
+                .iter()
+                .find(|noun| matches!(noun, DialNoun::Conference(_) | DialNoun::Queue(_)))
+            {
+                return Err(TwimlError::InvalidNesting {
+                    parent: "Dial".to_string(),
+                    child: format!("{} alongside other nouns", exclusive.name()),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Record {
+    pub action: Option<String>,
+    pub method: Option<String>,
+    pub timeout: Option<u32>,
+    pub finish_on_key: Option<String>,
+    pub max_length: Option<u32>,
+    pub play_beep: Option<bool>,
+    /// `trim-silence` or `do-not-trim`.
+    pub trim: Option<String>,
+    pub recording_status_callback: Option<String>,
+    pub recording_status_callback_event: Vec<String>,
+    pub transcribe: Option<bool>,
+    pub transcribe_callback: Option<String>,
+}
+
+impl Record {
+    pub fn new(action: &str) -> Self {
+        Self {
+            action: Some(action.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        TwimlElement::new("Record")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("timeout", self.timeout)
+            .attr("finishOnKey", self.finish_on_key.as_ref())
+            .attr("maxLength", self.max_length)
+            .attr("playBeep", self.play_beep)
+            .attr("trim", self.trim.as_ref())
+            .attr(
+                "recordingStatusCallback",
+                self.recording_status_callback.as_ref(),
+            )
+            .list_attr(
+                "recordingStatusCallbackEvent",
+                &self.recording_status_callback_event,
+                " ",
+            )
+            .attr("transcribe", self.transcribe)
+            .attr("transcribeCallback", self.transcribe_callback.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Enqueue {
+    pub name: String,
+    pub action: Option<String>,
+    pub method: Option<String>,
+    /// TwiML played to the caller while waiting.
+    pub wait_url: Option<String>,
+    pub wait_url_method: Option<String>,
+    pub workflow_sid: Option<String>,
+}
+
+impl Enqueue {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        TwimlElement::new("Enqueue")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .attr("waitUrl", self.wait_url.as_ref())
+            .attr("waitUrlMethod", self.wait_url_method.as_ref())
+            .attr("workflowSid", self.workflow_sid.as_ref())
+            .text_child(&self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Redirect {
+    pub url: String,
+    pub method: Option<String>,
+}
+
+impl Redirect {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            method: None,
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        TwimlElement::new("Redirect")
+            .attr("method", self.method.as_ref())
+            .text_child(&self.url)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectReason {
+    Rejected,
+    Busy,
+}
+
+impl RejectReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RejectReason::Rejected => "rejected",
+            RejectReason::Busy => "busy",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Reject {
+    pub reason: Option<RejectReason>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamTrack {
+    InboundTrack,
+    OutboundTrack,
+    BothTracks,
+}
+
+impl StreamTrack {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StreamTrack::InboundTrack => "inbound_track",
+            StreamTrack::OutboundTrack => "outbound_track",
+            StreamTrack::BothTracks => "both_tracks",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Stream {
+    /// `wss://` URL of the Media Streams server.
+    pub url: String,
+    pub name: Option<String>,
+    pub track: Option<StreamTrack>,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<String>,
+    pub parameters: Vec<Parameter>,
+}
+
+impl Stream {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        let mut element = TwimlElement::new("Stream")
+            .attr("url", Some(&self.url))
+            .attr("name", self.name.as_ref())
+            .attr("track", self.track.map(|track| track.as_str()))
+            .attr("statusCallback", self.status_callback.as_ref())
+            .attr("statusCallbackMethod", self.status_callback_method.as_ref());
+        for parameter in &self.parameters {
+            element = element.child(parameter.to_element());
+        }
+        element
+    }
+
+    fn validate(&self) -> Result<(), TwimlError> {
+        if !self.url.starts_with("wss://") {
+            return Err(TwimlError::InvalidAttribute {
+                element: "Stream",
+                attribute: "url",
+                reason: "must be a wss:// URL".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Connect {
+    pub stream: Stream,
+    pub action: Option<String>,
+    pub method: Option<String>,
+}
+
+impl Connect {
+    pub fn stream(stream: Stream) -> Self {
+        Self {
+            stream,
+            action: None,
+            method: None,
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        TwimlElement::new("Connect")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .child(self.stream.to_element())
+    }
+
+    fn validate(&self) -> Result<(), TwimlError> {
+        self.stream.validate()
+    }
+}
+
+/// Forks call audio to a Media Streams server while the call continues
+/// (`<Start><Stream>`), as opposed to `<Connect>` which hands the call over.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Start {
+    pub stream: Stream,
+    pub action: Option<String>,
+    pub method: Option<String>,
+}
+
+impl Start {
+    pub fn stream(stream: Stream) -> Self {
+        Self {
+            stream,
+            action: None,
+            method: None,
+        }
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        TwimlElement::new("Start")
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref())
+            .child(self.stream.to_element())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VoiceVerb {
+    Say(Say),
+    Play(Play),
+    Pause(Pause),
+    Gather(Gather),
+    Dial(Dial),
+    Record(Record),
+    Enqueue(Enqueue),
+    Redirect(Redirect),
+    Reject(Reject),
+    Hangup,
+    /// Takes the caller out of the queue from an `<Enqueue>` wait URL.
+    Leave,
+    Connect(Connect),
+    Start(Start),
+}
+
+impl VoiceVerb {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VoiceVerb::Say(_) => "Say",
+            VoiceVerb::Play(_) => "Play",
+            VoiceVerb::Pause(_) => "Pause",
+            VoiceVerb::Gather(_) => "Gather",
+            VoiceVerb::Dial(_) => "Dial",
+            VoiceVerb::Record(_) => "Record",
+            VoiceVerb::Enqueue(_) => "Enqueue",
+            VoiceVerb::Redirect(_) => "Redirect",
+            VoiceVerb::Reject(_) => "Reject",
+            VoiceVerb::Hangup => "Hangup",
+            VoiceVerb::Leave => "Leave",
+            VoiceVerb::Connect(_) => "Connect",
+            VoiceVerb::Start(_) => "Start",
+        }
+    }
+
+    /// Verbs after which Twilio never executes anything else in the document.
+    fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            VoiceVerb::Redirect(_) | VoiceVerb::Reject(_) | VoiceVerb::Hangup | VoiceVerb::Leave
+        )
+    }
+
+    pub fn to_element(&self) -> TwimlElement {
+        match self {
+            VoiceVerb::Say(say) => say.to_element(),
+            VoiceVerb::Play(play) => play.to_element(),
+            VoiceVerb::Pause(pause) => pause.to_element(),
+            VoiceVerb::Gather(gather) => gather.to_element(),
+            VoiceVerb::Dial(dial) => dial.to_element(),
+            VoiceVerb::Record(record) => record.to_element(),
+            VoiceVerb::Enqueue(enqueue) => enqueue.to_element(),
+            VoiceVerb::Redirect(redirect) => redirect.to_element(),
+            VoiceVerb::Reject(reject) => {
+                TwimlElement::new("Reject").attr("reason", reject.reason.map(|r| r.as_str()))
+            }
+            VoiceVerb::Hangup => TwimlElement::new("Hangup"),
+            VoiceVerb::Leave => TwimlElement::new("Leave"),
+            VoiceVerb::Connect(connect) => connect.to_element(),
+            VoiceVerb::Start(start) => start.to_element(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), TwimlError> {
+        match self {
+            VoiceVerb::Say(say) => say.validate(),
+            VoiceVerb::Play(play) => play.validate(),
+            VoiceVerb::Gather(gather) => gather.validate(),
+            VoiceVerb::Dial(dial) => dial.validate(),
+            VoiceVerb::Enqueue(enqueue) if enqueue.name.trim().is_empty() => {
+                if enqueue.workflow_sid.is_some() {
+                    Ok(())
+                } else {
+                    Err(TwimlError::Empty("Enqueue"))
+                }
+            }
+            VoiceVerb::Redirect(redirect) if redirect.url.trim().is_empty() => {
+                Err(TwimlError::Empty("Redirect"))
+            }
+            VoiceVerb::Connect(connect) => connect.validate(),
+            VoiceVerb::Start(start) => start.stream.validate(),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Builds the TwiML returned to Twilio for a voice call.
+///
+/// # Example
+/// ```
+/// use twilio_rs::twiml::{Gather, GatherInput, Say, VoiceResponse};
+///
+/// let mut gather = Gather::new("/ivr/menu").say(Say::new("Press 1 for sales."));
+/// gather.input = vec![GatherInput::Dtmf, GatherInput::Speech];
+///
+/// let xml = VoiceResponse::new()
+///     .gather(gather)
+///     .say(Say::new("We didn't receive any input. Goodbye!"))
+///     .to_xml()
+///     .unwrap();
+/// assert!(xml.contains(r#"<Gather input="dtmf speech" action="/ivr/menu">"#));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VoiceResponse {
+    pub verbs: Vec<VoiceVerb>,
+}
+
+impl VoiceResponse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn verb(mut self, verb: VoiceVerb) -> Self {
+        self.verbs.push(verb);
+        self
+    }
+
+    pub fn say(self, say: Say) -> Self {
+        self.verb(VoiceVerb::Say(say))
+    }
+
+    pub fn play(self, play: Play) -> Self {
+        self.verb(VoiceVerb::Play(play))
+    }
+
+    pub fn pause(self, pause: Pause) -> Self {
+        self.verb(VoiceVerb::Pause(pause))
+    }
+
+    pub fn gather(self, gather: Gather) -> Self {
+        self.verb(VoiceVerb::Gather(gather))
+    }
+
+    pub fn dial(self, dial: Dial) -> Self {
+        self.verb(VoiceVerb::Dial(dial))
+    }
+
+    pub fn record(self, record: Record) -> Self {
+        self.verb(VoiceVerb::Record(record))
+    }
+
+    pub fn enqueue(self, enqueue: Enqueue) -> Self {
+        self.verb(VoiceVerb::Enqueue(enqueue))
+    }
+
+    pub fn redirect(self, redirect: Redirect) -> Self {
+        self.verb(VoiceVerb::Redirect(redirect))
+    }
+
+    pub fn reject(self, reject: Reject) -> Self {
+        self.verb(VoiceVerb::Reject(reject))
+    }
+
+    pub fn hangup(self) -> Self {
+        self.verb(VoiceVerb::Hangup)
+    }
+
+    pub fn leave(self) -> Self {
+        self.verb(VoiceVerb::Leave)
+    }
+
+    pub fn connect(self, connect: Connect) -> Self {
+        self.verb(VoiceVerb::Connect(connect))
+    }
+
+    pub fn start(self, start: Start) -> Self {
+        self.verb(VoiceVerb::Start(start))
+    }
+
+    pub fn validate(&self) -> Result<(), TwimlError> {
+        let mut terminal: Option<&'static str> = None;
+        for verb in &self.verbs {
+            if let Some(after) = terminal {
+                return Err(TwimlError::UnreachableVerb {
+                    verb: verb.name(),
+                    after,
+                });
+            }
+            verb.validate()?;
+            if verb.is_terminal() {
+                terminal = Some(verb.name());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_element(&self) -> TwimlElement {
+        let mut response = TwimlElement::new("Response");
+        for verb in &self.verbs {
+            response = response.child(verb.to_element());
+        }
+        response
+    }
+
+    /// Validates the response and renders it as a TwiML document.
+    pub fn to_xml(&self) -> Result<String, TwimlError> {
+        self.validate()?;
+        let mut out = String::from(XML_DECLARATION);
+        self.to_element().write(&mut out);
+        Ok(out)
+    }
+}
+
+/// WhatsApp and MMS accept at most this many `<Media>` per `<Message>`.
+pub const MAX_MESSAGE_MEDIA: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Message {
+    pub body: Option<String>,
+    pub media: Vec<String>,
+    /// Defaults to the sender of the inbound message.
+    pub to: Option<String>,
+    pub from: Option<String>,
+    pub action: Option<String>,
+    pub method: Option<String>,
+}
+
+impl Message {
+    pub fn new(body: &str) -> Self {
+        Self {
+            body: Some(body.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn media(mut self, url: &str) -> Self {
+        self.media.push(url.to_string());
+        self
+    }
+
+    fn to_element(&self) -> TwimlElement {
+        let mut element = TwimlElement::new("Message")
+            .attr("to", self.to.as_ref())
+            .attr("from", self.from.as_ref())
+            .attr("action", self.action.as_ref())
+            .attr("method", self.method.as_ref());
+        if self.media.is_empty() {
+            if let Some(body) = &self.body {
+                return element.text_child(body);
+            }
+        }
+        if let Some(body) = &self.body {
+            element = element.child(TwimlElement::new("Body").text_child(body));
+        }
+        for url in &self.media {
+            element = element.child(TwimlElement::new("Media").text_child(url));
+        }
+        element
+    }
+
+    fn validate(&self) -> Result<(), TwimlError> {
+        if self.body.as_deref().unwrap_or("").is_empty() && self.media.is_empty() {
+            return Err(TwimlError::Empty("Message"));
+        }
+        if self.media.len() > MAX_MESSAGE_MEDIA {
+            return Err(TwimlError::InvalidNesting {
+                parent: "Message".to_string(),
+                child: format!("more than {} Media", MAX_MESSAGE_MEDIA),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessagingVerb {
+    Message(Message),
+    Redirect(Redirect),
+}
+
+impl MessagingVerb {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MessagingVerb::Message(_) => "Message",
+            MessagingVerb::Redirect(_) => "Redirect",
+        }
+    }
+
+    pub fn to_element(&self) -> TwimlElement {
+        match self {
+            MessagingVerb::Message(message) => message.to_element(),
+            MessagingVerb::Redirect(redirect) => redirect.to_element(),
+        }
+    }
+}
+
+/// Builds the TwiML returned to Twilio for an inbound SMS or WhatsApp message.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MessagingResponse {
+    pub verbs: Vec<MessagingVerb>,
+}
+
+impl MessagingResponse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn message(mut self, message: Message) -> Self {
+        self.verbs.push(MessagingVerb::Message(message));
+        self
+    }
+
+    pub fn redirect(mut self, redirect: Redirect) -> Self {
+        self.verbs.push(MessagingVerb::Redirect(redirect));
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), TwimlError> {
+        let mut redirected = false;
+        for verb in &self.verbs {
+            if redirected {
+                return Err(TwimlError::UnreachableVerb {
+                    verb: verb.name(),
+                    after: "Redirect",
+                });
+            }
+            match verb {
+                MessagingVerb::Message(message) => message.validate()?,
+                MessagingVerb::Redirect(redirect) if redirect.url.trim().is_empty() => {
+                    return Err(TwimlError::Empty("Redirect"))
+                }
+                MessagingVerb::Redirect(_) => redirected = true,
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_element(&self) -> TwimlElement {
+        let mut response = TwimlElement::new("Response");
+        for verb in &self.verbs {
+            response = response.child(verb.to_element());
+        }
+        response
+    }
+
+    /// Validates the response and renders it as a TwiML document.
+    pub fn to_xml(&self) -> Result<String, TwimlError> {
+        self.validate()?;
+        let mut out = String::from(XML_DECLARATION);
+        self.to_element().write(&mut out);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nesting(parent: &str, child: &str) -> TwimlError {
+        TwimlError::InvalidNesting {
+            parent: parent.to_string(),
+            child: child.to_string(),
+        }
+    }
+
+    #[test]
+    fn escapes_text_and_attributes() {
+        assert_eq!(
+            escape_xml(r#"Tom & Jerry <3 "hi" 'there'>"#),
+            "Tom &amp; Jerry &lt;3 &quot;hi&quot; &apos;there&apos;&gt;"
+        );
+        assert_eq!(escape_xml("already &amp;"), "already &amp;amp;");
+
+        let xml = VoiceResponse::new()
+            .say(Say::new("1 < 2 & 3").with_voice(r#"Polly."Joanna""#))
+            .redirect(Redirect::new("/next?a=1&b=2"))
+            .to_xml()
+            .unwrap();
+        assert!(
+            xml.contains(r#"<Say voice="Polly.&quot;Joanna&quot;">1 &lt; 2 &amp; 3</Say>"#),
+            "{}",
+            xml
+        );
+        assert!(
+            xml.contains("<Redirect>/next?a=1&amp;b=2</Redirect>"),
+            "{}",
+            xml
+        );
+    }
+
+    #[test]
+    fn invalid_dial_nouns_are_rejected() {
+        let dial = |dial: Dial| VoiceResponse::new().dial(dial).validate();
+        assert_eq!(dial(Dial::new()), Err(TwimlError::Empty("Dial")));
+        assert_eq!(dial(Dial::number(" ")), Err(TwimlError::Empty("Dial")));
+        assert_eq!(
+            dial(
+                Dial::number("+14155550100").noun(DialNoun::Conference(Conference::new("standup")))
+            ),
+            Err(nesting("Dial", "Conference alongside other nouns"))
+        );
+        assert_eq!(
+            dial(
+                Dial::new()
+                    .noun(DialNoun::Queue(Queue::new("support")))
+                    .noun(DialNoun::Client(Client::new("alice")))
+            ),
+            Err(nesting("Dial", "Queue alongside other nouns"))
+        );
+
+        let full = (0..MAX_DIAL_NOUNS).fold(Dial::new(), |dial, i| {
+            dial.noun(DialNoun::Client(Client::new(&format!("agent{}", i))))
+        });
+        assert_eq!(dial(full.clone()), Ok(()));
+        assert_eq!(
+            dial(full.noun(DialNoun::Sip(Sip::new("sip:a@example.com")))),
+            Err(nesting("Dial", "more than 10 nouns"))
+        );
+    }
+
+    #[test]
+    fn invalid_gather_children_are_rejected() {
+        let gather = |gather: Gather| VoiceResponse::new().gather(gather).validate();
+        assert_eq!(gather(Gather::new("/menu")), Ok(()));
+        assert_eq!(
+            gather(Gather::new("/menu").say(Say::new("  "))),
+            Err(TwimlError::Empty("Say"))
+        );
+        assert_eq!(
+            gather(Gather::new("/menu").play(Play::default())),
+            Err(TwimlError::Empty("Play"))
+        );
+
+        let mut no_digits = Gather::new("/menu");
+        no_digits.num_digits = Some(0);
+        assert!(matches!(
+            gather(no_digits),
+            Err(TwimlError::InvalidAttribute {
+                element: "Gather",
+                attribute: "numDigits",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn connect_and_start_streams_need_websocket_urls() {
+        let insecure = Stream::new("https://example.com/stream");
+        for response in [
+            VoiceResponse::new().connect(Connect::stream(insecure.clone())),
+            VoiceResponse::new().start(Start::stream(insecure)),
+        ] {
+            assert!(matches!(
+                response.validate(),
+                Err(TwimlError::InvalidAttribute {
+                    element: "Stream",
+                    attribute: "url",
+                    ..
+                })
+            ));
+        }
+        let stream = Stream::new("wss://example.com/stream");
+        assert_eq!(
+            VoiceResponse::new()
+                .connect(Connect::stream(stream))
+                .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verbs_after_a_terminal_verb_are_rejected() {
+        assert_eq!(
+            VoiceResponse::new()
+                .hangup()
+                .say(Say::new("Bye"))
+                .validate(),
+            Err(TwimlError::UnreachableVerb {
+                verb: "Say",
+                after: "Hangup",
+            })
+        );
+    }
+
+    #[test]
+    fn ivr_document() {
+        let mut menu = Gather::new("/ivr/menu")
+            .say(Say::new("Press 1 for sales, or 2 for support.").with_language("en-GB"))
+            .pause(Pause::new(1));
+        menu.input = vec![GatherInput::Dtmf, GatherInput::Speech];
+        menu.num_digits = Some(1);
+        menu.hints = vec!["sales".to_string(), "support".to_string()];
+
+        let mut sales = Dial::new()
+            .noun(DialNoun::Number(Number::new("+14155550100")))
+            .noun(DialNoun::Client(Client {
+                parameters: vec![Parameter::new("queue", "sales")],
+                ..Client::new("alice")
+            }));
+        sales.timeout = Some(20);
+        sales.caller_id = Some("+14155550199".to_string());
+
+        let xml = VoiceResponse::new()
+            .gather(menu)
+            .dial(sales)
+            .say(Say::ssml(vec![
+                SsmlNode::Text("Sorry, nobody is available. ".to_string()),
+                SsmlNode::Break {
+                    strength: None,
+                    time: Some("500ms".to_string()),
+                },
+                SsmlNode::SayAs {
+                    interpret_as: "telephone".to_string(),
+                    format: None,
+                    text: "+14155550100".to_string(),
+                },
+            ]))
+            .record(Record {
+                max_length: Some(120),
+                play_beep: Some(true),
+                ..Record::new("/ivr/voicemail")
+            })
+            .hangup()
+            .to_xml()
+            .unwrap();
+
+        assert_eq!(
+            xml,
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8"?><Response>"#,
+                r#"<Gather input="dtmf speech" action="/ivr/menu" numDigits="1" hints="sales,support">"#,
+                r#"<Say language="en-GB">Press 1 for sales, or 2 for support.</Say>"#,
+                r#"<Pause length="1"/></Gather>"#,
+                r#"<Dial timeout="20" callerId="+14155550199">"#,
+                r#"<Number>+14155550100</Number>"#,
+                r#"<Client><Identity>alice</Identity><Parameter name="queue" value="sales"/></Client>"#,
+                r#"</Dial>"#,
+                r#"<Say>Sorry, nobody is available. <break time="500ms"/>"#,
+                r#"<say-as interpret-as="telephone">+14155550100</say-as></Say>"#,
+                r#"<Record action="/ivr/voicemail" maxLength="120" playBeep="true"/>"#,
+                r#"<Hangup/></Response>"#,
+            )
+        );
+    }
+}
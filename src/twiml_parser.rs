@@ -0,0 +1,701 @@
+use crate::twiml::{
+    Client, Conference, Connect, Dial, DialNoun, Enqueue, Gather, GatherChild, GatherInput,
+    Message, MessagingResponse, MessagingVerb, Number, Parameter, Pause, Play, Queue, Record,
+    Redirect, Reject, RejectReason, Say, Sip, SsmlNode, Start, Stream, StreamTrack, TwimlElement,
+    TwimlError, TwimlNode, VoiceResponse, VoiceVerb,
+};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+fn malformed(error: impl std::fmt::Display) -> TwimlError {
+    TwimlError::Malformed(error.to_string())
+}
+
+fn start_element(start: &BytesStart) -> Result<TwimlElement, TwimlError> {
+    let mut element = TwimlElement::new(&String::from_utf8_lossy(start.name().as_ref()));
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(malformed)?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        let value = attribute.unescape_value().map_err(malformed)?.into_owned();
+        element.attributes.push((key, value));
+    }
+    Ok(element)
+}
+
+fn push_node(stack: &mut [TwimlElement], node: TwimlNode) -> Result<(), TwimlError> {
+    match stack.last_mut() {
+        Some(parent) => {
+            parent.children.push(node);
+            Ok(())
+        }
+        None => Err(malformed("content outside the root element")),
+    }
+}
+
+/// A document has exactly one root element, so any later top-level element
+/// is an error rather than a replacement.
+fn second_root(root: &Option<TwimlElement>, element: &TwimlElement) -> Result<(), TwimlError> {
+    match root {
+        Some(root) => Err(malformed(format!(
+            "<{}> follows the root element <{}>",
+            element.name, root.name
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Reads any TwiML document into a generic element tree rooted at
+/// `<Response>`. Whitespace-only text between elements is dropped.
+pub fn parse_element(xml: &str) -> Result<TwimlElement, TwimlError> {
+    let mut reader = Reader::from_str(xml);
+    let mut stack: Vec<TwimlElement> = Vec::new();
+    let mut root: Option<TwimlElement> = None;
+
+    loop {
+        match reader.read_event().map_err(malformed)? {
+            Event::Start(start) => {
+                let element = start_element(&start)?;
+                if stack.is_empty() {
+                    second_root(&root, &element)?;
+                }
+                stack.push(element);
+            }
+            Event::Empty(start) => {
+                let element = start_element(&start)?;
+                if stack.is_empty() {
+                    second_root(&root, &element)?;
+                    root = Some(element);
+                } else {
+                    push_node(&mut stack, TwimlNode::Element(element))?;
+                }
+            }
+            Event::End(_) => {
+                let element = stack
+                    .pop()
+                    .ok_or_else(|| malformed("unexpected closing tag"))?;
+                if stack.is_empty() {
+                    root = Some(element);
+                } else {
+                    push_node(&mut stack, TwimlNode::Element(element))?;
+                }
+            }
+            Event::Text(text) => {
+                let text = text.unescape().map_err(malformed)?;
+                if !text.trim().is_empty() {
+                    push_node(&mut stack, TwimlNode::Text(text.into_owned()))?;
+                }
+            }
+            Event::CData(data) => {
+                let text = String::from_utf8_lossy(&data.into_inner()).into_owned();
+                push_node(&mut stack, TwimlNode::Text(text))?;
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(malformed("unclosed element"));
+    }
+    let root = root.ok_or_else(|| malformed("document is empty"))?;
+    if root.name != "Response" {
+        return Err(malformed(format!(
+            "root element must be <Response>, found <{}>",
+            root.name
+        )));
+    }
+    Ok(root)
+}
+
+fn opt(element: &TwimlElement, name: &str) -> Option<String> {
+    element.attribute(name).map(str::to_string)
+}
+
+fn number(
+    element: &TwimlElement,
+    name: &'static str,
+    attribute: &'static str,
+) -> Result<Option<u32>, TwimlError> {
+    element
+        .attribute(attribute)
+        .map(|value| {
+            value
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| TwimlError::InvalidAttribute {
+                    element: name,
+                    attribute,
+                    reason: format!("'{}' is not a whole number", value),
+                })
+        })
+        .transpose()
+}
+
+fn boolean(
+    element: &TwimlElement,
+    name: &'static str,
+    attribute: &'static str,
+) -> Result<Option<bool>, TwimlError> {
+    element
+        .attribute(attribute)
+        .map(|value| match value.trim() {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            other => Err(TwimlError::InvalidAttribute {
+                element: name,
+                attribute,
+                reason: format!("'{}' is not true or false", other),
+            }),
+        })
+        .transpose()
+}
+
+fn list(element: &TwimlElement, attribute: &str, separator: char) -> Vec<String> {
+    element
+        .attribute(attribute)
+        .map(|value| {
+            value
+                .split(separator)
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn trimmed_text(element: &TwimlElement) -> String {
+    element.text().trim().to_string()
+}
+
+/// Rejects child elements on nouns that only hold text.
+fn text_only(element: &TwimlElement) -> Result<String, TwimlError> {
+    if let Some(child) = element.child_elements().next() {
+        return Err(nested(&element.name, &child.name));
+    }
+    Ok(trimmed_text(element))
+}
+
+fn nested(parent: &str, child: &str) -> TwimlError {
+    TwimlError::InvalidNesting {
+        parent: parent.to_string(),
+        child: child.to_string(),
+    }
+}
+
+fn ssml_nodes(element: &TwimlElement) -> Result<Vec<SsmlNode>, TwimlError> {
+    element
+        .children
+        .iter()
+        .map(|child| match child {
+            TwimlNode::Text(text) => Ok(SsmlNode::Text(text.clone())),
+            TwimlNode::Element(child) => ssml_node(&element.name, child),
+        })
+        .collect()
+}
+
+fn ssml_node(parent: &str, element: &TwimlElement) -> Result<SsmlNode, TwimlError> {
+    Ok(match element.name.as_str() {
+        "break" => SsmlNode::Break {
+            strength: opt(element, "strength"),
+            time: opt(element, "time"),
+        },
+        "emphasis" => SsmlNode::Emphasis {
+            level: opt(element, "level"),
+            children: ssml_nodes(element)?,
+        },
+        "prosody" => SsmlNode::Prosody {
+            rate: opt(element, "rate"),
+            pitch: opt(element, "pitch"),
+            volume: opt(element, "volume"),
+            children: ssml_nodes(element)?,
+        },
+        "say-as" => SsmlNode::SayAs {
+            interpret_as: opt(element, "interpret-as").unwrap_or_default(),
+            format: opt(element, "format"),
+            text: text_only(element)?,
+        },
+        "phoneme" => SsmlNode::Phoneme {
+            alphabet: opt(element, "alphabet"),
+            ph: opt(element, "ph").unwrap_or_default(),
+            text: text_only(element)?,
+        },
+        "sub" => SsmlNode::Sub {
+            alias: opt(element, "alias").unwrap_or_default(),
+            text: text_only(element)?,
+        },
+        "lang" => SsmlNode::Lang {
+            lang: opt(element, "xml:lang").unwrap_or_default(),
+            children: ssml_nodes(element)?,
+        },
+        "p" => SsmlNode::Paragraph(ssml_nodes(element)?),
+        "s" => SsmlNode::Sentence(ssml_nodes(element)?),
+        other => return Err(nested(parent, other)),
+    })
+}
+
+fn say(element: &TwimlElement) -> Result<Say, TwimlError> {
+    Ok(Say {
+        content: ssml_nodes(element)?,
+        voice: opt(element, "voice"),
+        language: opt(element, "language"),
+        loop_count: number(element, "Say", "loop")?,
+    })
+}
+
+fn play(element: &TwimlElement) -> Result<Play, TwimlError> {
+    let url = text_only(element)?;
+    Ok(Play {
+        url: (!url.is_empty()).then_some(url),
+        loop_count: number(element, "Play", "loop")?,
+        digits: opt(element, "digits"),
+    })
+}
+
+fn pause(element: &TwimlElement) -> Result<Pause, TwimlError> {
+    text_only(element)?;
+    Ok(Pause {
+        length: number(element, "Pause", "length")?,
+    })
+}
+
+fn gather(element: &TwimlElement) -> Result<Gather, TwimlError> {
+    let input = list(element, "input", ' ')
+        .iter()
+        .map(|input| match input.as_str() {
+            "dtmf" => Ok(GatherInput::Dtmf),
+            "speech" => Ok(GatherInput::Speech),
+            other => Err(TwimlError::InvalidAttribute {
+                element: "Gather",
+                attribute: "input",
+                reason: format!("unknown input '{}'", other),
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let children = element
+        .child_elements()
+        .map(|child| match child.name.as_str() {
+            "Say" => say(child).map(GatherChild::Say),
+            "Play" => play(child).map(GatherChild::Play),
+            "Pause" => pause(child).map(GatherChild::Pause),
+            other => Err(nested("Gather", other)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Gather {
+        input,
+        action: opt(element, "action"),
+        method: opt(element, "method"),
+        timeout: number(element, "Gather", "timeout")?,
+        speech_timeout: opt(element, "speechTimeout"),
+        num_digits: number(element, "Gather", "numDigits")?,
+        finish_on_key: opt(element, "finishOnKey"),
+        hints: list(element, "hints", ','),
+        language: opt(element, "language"),
+        speech_model: opt(element, "speechModel"),
+        enhanced: boolean(element, "Gather", "enhanced")?,
+        profanity_filter: boolean(element, "Gather", "profanityFilter")?,
+        partial_result_callback: opt(element, "partialResultCallback"),
+        action_on_empty_result: boolean(element, "Gather", "actionOnEmptyResult")?,
+        children,
+    })
+}
+
+fn parameters(element: &TwimlElement) -> Vec<Parameter> {
+    element
+        .child_elements()
+        .filter(|child| child.name == "Parameter")
+        .map(|child| Parameter {
+            name: opt(child, "name").unwrap_or_default(),
+            value: opt(child, "value").unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn dial_noun(element: &TwimlElement) -> Result<DialNoun, TwimlError> {
+    Ok(match element.name.as_str() {
+        "Number" => DialNoun::Number(Number {
+            number: text_only(element)?,
+            send_digits: opt(element, "sendDigits"),
+            url: opt(element, "url"),
+            method: opt(element, "method"),
+            status_callback: opt(element, "statusCallback"),
+            status_callback_event: list(element, "statusCallbackEvent", ' '),
+        }),
+        "Client" => {
+            for child in element.child_elements() {
+                if child.name != "Identity" && child.name != "Parameter" {
+                    return Err(nested("Client", &child.name));
+                }
+            }
+            let identity = match element.child_elements().find(|c| c.name == "Identity") {
+                Some(identity) => text_only(identity)?,
+                None => trimmed_text(element),
+            };
+            DialNoun::Client(Client {
+                identity,
+                url: opt(element, "url"),
+                method: opt(element, "method"),
+                parameters: parameters(element),
+            })
+        }
+        "Sip" => DialNoun::Sip(Sip {
+            uri: text_only(element)?,
+            username: opt(element, "username"),
+            password: opt(element, "password"),
+            url: opt(element, "url"),
+            method: opt(element, "method"),
+        }),
+        "Conference" => DialNoun::Conference(Conference {
+            name: text_only(element)?,
+            muted: boolean(element, "Conference", "muted")?,
+            beep: opt(element, "beep"),
+            start_conference_on_enter: boolean(element, "Conference", "startConferenceOnEnter")?,
+            end_conference_on_exit: boolean(element, "Conference", "endConferenceOnExit")?,
+            wait_url: opt(element, "waitUrl"),
+            wait_method: opt(element, "waitMethod"),
+            max_participants: number(element, "Conference", "maxParticipants")?,
+            record: opt(element, "record"),
+            status_callback: opt(element, "statusCallback"),
+            status_callback_event: list(element, "statusCallbackEvent", ' '),
+            coach: opt(element, "coach"),
+            participant_label: opt(element, "participantLabel"),
+        }),
+        "Queue" => DialNoun::Queue(Queue {
+            name: text_only(element)?,
+            url: opt(element, "url"),
+            method: opt(element, "method"),
+        }),
+        other => return Err(nested("Dial", other)),
+    })
+}
+
+fn dial(element: &TwimlElement) -> Result<Dial, TwimlError> {
+    let mut nouns = element
+        .child_elements()
+        .map(dial_noun)
+        .collect::<Result<Vec<_>, _>>()?;
+    // `<Dial>+15558675310</Dial>` is shorthand for a single `<Number>`.
+    let text = trimmed_text(element);
+    if !text.is_empty() {
+        nouns.insert(0, DialNoun::Number(Number::new(&text)));
+    }
+
+    Ok(Dial {
+        nouns,
+        action: opt(element, "action"),
+        method: opt(element, "method"),
+        timeout: number(element, "Dial", "timeout")?,
+        time_limit: number(element, "Dial", "timeLimit")?,
+        caller_id: opt(element, "callerId"),
+        record: opt(element, "record"),
+        recording_status_callback: opt(element, "recordingStatusCallback"),
+        hangup_on_star: boolean(element, "Dial", "hangupOnStar")?,
+        answer_on_bridge: boolean(element, "Dial", "answerOnBridge")?,
+        ring_tone: opt(element, "ringTone"),
+    })
+}
+
+fn record(element: &TwimlElement) -> Result<Record, TwimlError> {
+    text_only(element)?;
+    Ok(Record {
+        action: opt(element, "action"),
+        method: opt(element, "method"),
+        timeout: number(element, "Record", "timeout")?,
+        finish_on_key: opt(element, "finishOnKey"),
+        max_length: number(element, "Record", "maxLength")?,
+        play_beep: boolean(element, "Record", "playBeep")?,
+        trim: opt(element, "trim"),
+        recording_status_callback: opt(element, "recordingStatusCallback"),
+        recording_status_callback_event: list(element, "recordingStatusCallbackEvent", ' '),
+        transcribe: boolean(element, "Record", "transcribe")?,
+        transcribe_callback: opt(element, "transcribeCallback"),
+    })
+}
+
+fn redirect(element: &TwimlElement) -> Result<Redirect, TwimlError> {
+    Ok(Redirect {
+        url: text_only(element)?,
+        method: opt(element, "method"),
+    })
+}
+
+fn stream(element: &TwimlElement) -> Result<Stream, TwimlError> {
+    for child in element.child_elements() {
+        if child.name != "Parameter" {
+            return Err(nested("Stream", &child.name));
+        }
+    }
+    let track = element
+        .attribute("track")
+        .map(|track| match track {
+            "inbound_track" => Ok(StreamTrack::InboundTrack),
+            "outbound_track" => Ok(StreamTrack::OutboundTrack),
+            "both_tracks" => Ok(StreamTrack::BothTracks),
+            other => Err(TwimlError::InvalidAttribute {
+                element: "Stream",
+                attribute: "track",
+                reason: format!("unknown track '{}'", other),
+            }),
+        })
+        .transpose()?;
+
+    Ok(Stream {
+        url: opt(element, "url").unwrap_or_default(),
+        name: opt(element, "name"),
+        track,
+        status_callback: opt(element, "statusCallback"),
+        status_callback_method: opt(element, "statusCallbackMethod"),
+        parameters: parameters(element),
+    })
+}
+
+/// The single `<Stream>` inside a `<Connect>` or `<Start>`.
+fn only_stream(element: &TwimlElement, parent: &'static str) -> Result<Stream, TwimlError> {
+    let mut children = element.child_elements();
+    match (children.next(), children.next()) {
+        (Some(child), None) if child.name == "Stream" => stream(child),
+        (Some(child), None) => Err(nested(parent, &child.name)),
+        (None, _) => Err(TwimlError::Empty(parent)),
+        (Some(_), Some(extra)) => Err(nested(parent, &extra.name)),
+    }
+}
+
+fn connect(element: &TwimlElement) -> Result<Connect, TwimlError> {
+    Ok(Connect {
+        stream: only_stream(element, "Connect")?,
+        action: opt(element, "action"),
+        method: opt(element, "method"),
+    })
+}
+
+fn start(element: &TwimlElement) -> Result<Start, TwimlError> {
+    Ok(Start {
+        stream: only_stream(element, "Start")?,
+        action: opt(element, "action"),
+        method: opt(element, "method"),
+    })
+}
+
+fn voice_verb(element: &TwimlElement) -> Result<VoiceVerb, TwimlError> {
+    Ok(match element.name.as_str() {
+        "Say" => VoiceVerb::Say(say(element)?),
+        "Play" => VoiceVerb::Play(play(element)?),
+        "Pause" => VoiceVerb::Pause(pause(element)?),
+        "Gather" => VoiceVerb::Gather(gather(element)?),
+        "Dial" => VoiceVerb::Dial(dial(element)?),
+        "Record" => VoiceVerb::Record(record(element)?),
+        "Enqueue" => VoiceVerb::Enqueue(Enqueue {
+            name: text_only(element)?,
+            action: opt(element, "action"),
+            method: opt(element, "method"),
+            wait_url: opt(element, "waitUrl"),
+            wait_url_method: opt(element, "waitUrlMethod"),
+            workflow_sid: opt(element, "workflowSid"),
+        }),
+        "Redirect" => VoiceVerb::Redirect(redirect(element)?),
+        "Reject" => {
+            text_only(element)?;
+            let reason = element
+                .attribute("reason")
+                .map(|reason| match reason {
+                    "rejected" => Ok(RejectReason::Rejected),
+                    "busy" => Ok(RejectReason::Busy),
+                    other => Err(TwimlError::InvalidAttribute {
+                        element: "Reject",
+                        attribute: "reason",
+                        reason: format!("unknown reason '{}'", other),
+                    }),
+                })
+                .transpose()?;
+            VoiceVerb::Reject(Reject { reason })
+        }
+        "Hangup" => {
+            text_only(element)?;
+            VoiceVerb::Hangup
+        }
+        "Leave" => {
+            text_only(element)?;
+            VoiceVerb::Leave
+        }
+        "Connect" => VoiceVerb::Connect(connect(element)?),
+        "Start" => VoiceVerb::Start(start(element)?),
+        other => return Err(nested("Response", other)),
+    })
+}
+
+fn message(element: &TwimlElement) -> Result<Message, TwimlError> {
+    let mut message = Message {
+        to: opt(element, "to"),
+        from: opt(element, "from"),
+        action: opt(element, "action"),
+        method: opt(element, "method"),
+        ..Default::default()
+    };
+    let text = trimmed_text(element);
+    if !text.is_empty() {
+        message.body = Some(text);
+    }
+    for child in element.child_elements() {
+        match child.name.as_str() {
+            "Body" => message.body = Some(text_only(child)?),
+            "Media" => message.media.push(text_only(child)?),
+            other => return Err(nested("Message", other)),
+        }
+    }
+    Ok(message)
+}
+
+impl VoiceResponse {
+    /// Parses a voice TwiML document into typed verbs. Call `validate` on the
+    /// result to also check ordering and content rules.
+    pub fn parse(xml: &str) -> Result<Self, TwimlError> {
+        let root = parse_element(xml)?;
+        let verbs = root
+            .child_elements()
+            .map(voice_verb)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { verbs })
+    }
+}
+
+impl MessagingResponse {
+    /// Parses a messaging TwiML document into typed verbs.
+    pub fn parse(xml: &str) -> Result<Self, TwimlError> {
+        let root = parse_element(xml)?;
+        let verbs = root
+            .child_elements()
+            .map(|element| match element.name.as_str() {
+                "Message" => message(element).map(MessagingVerb::Message),
+                "Redirect" => redirect(element).map(MessagingVerb::Redirect),
+                other => Err(nested("Response", other)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { verbs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voice_round_trip(response: VoiceResponse) {
+        let xml = response.to_xml().expect("valid TwiML");
+        assert_eq!(VoiceResponse::parse(&xml), Ok(response), "{}", xml);
+    }
+
+    #[test]
+    fn voice_response_round_trips() {
+        voice_round_trip(
+            VoiceResponse::new()
+                .say(Say::new("Hello").with_voice("Polly.Joanna"))
+                .play(Play::new("https://example.com/hold.mp3"))
+                .pause(Pause::new(2))
+                .record(Record::new("/recorded"))
+                .redirect(Redirect::new("/next")),
+        );
+        voice_round_trip(VoiceResponse::new().hangup());
+    }
+
+    #[test]
+    fn messaging_response_round_trips() {
+        let response = MessagingResponse::new()
+            .message(Message::new("Thanks!").media("https://example.com/a.png"))
+            .redirect(Redirect::new("/fallback"));
+        let xml = response.to_xml().expect("valid TwiML");
+        assert_eq!(MessagingResponse::parse(&xml), Ok(response), "{}", xml);
+    }
+
+    #[test]
+    fn escaped_text_round_trips() {
+        voice_round_trip(VoiceResponse::new().say(Say::new("Tom & Jerry <3 \"quotes\"")));
+
+        let response = MessagingResponse::new().message(Message::new("a < b && c > d"));
+        let xml = response.to_xml().expect("valid TwiML");
+        assert!(xml.contains("a &lt; b &amp;&amp; c &gt; d"), "{}", xml);
+        assert_eq!(MessagingResponse::parse(&xml), Ok(response));
+    }
+
+    #[test]
+    fn nested_dial_and_gather_round_trip() {
+        voice_round_trip(
+            VoiceResponse::new()
+                .gather(
+                    Gather::new("/menu")
+                        .say(Say::new("Press 1 for sales"))
+                        .play(Play::new("https://example.com/menu.mp3"))
+                        .pause(Pause::new(1)),
+                )
+                .dial(
+                    Dial::number("+14155550100")
+                        .noun(DialNoun::Client(Client::new("alice")))
+                        .noun(DialNoun::Sip(Sip::new("sip:bob@example.com"))),
+                ),
+        );
+        voice_round_trip(
+            VoiceResponse::new().dial(Dial::new().noun(DialNoun::Queue(Queue::new("support")))),
+        );
+        voice_round_trip(
+            VoiceResponse::new()
+                .dial(Dial::new().noun(DialNoun::Conference(Conference::new("standup")))),
+        );
+    }
+
+    #[test]
+    fn bare_dial_text_is_a_number() {
+        let response =
+            VoiceResponse::parse("<Response><Dial>+14155550100</Dial></Response>").unwrap();
+        assert_eq!(
+            response,
+            VoiceResponse::new().dial(Dial::number("+14155550100"))
+        );
+    }
+
+    #[test]
+    fn unknown_verbs_are_rejected() {
+        let bogus = TwimlError::InvalidNesting {
+            parent: "Response".to_string(),
+            child: "Bogus".to_string(),
+        };
+        assert_eq!(
+            VoiceResponse::parse("<Response><Bogus/></Response>"),
+            Err(bogus.clone())
+        );
+        assert_eq!(
+            MessagingResponse::parse("<Response><Bogus/></Response>"),
+            Err(bogus)
+        );
+        assert!(matches!(
+            VoiceResponse::parse("<Response><Gather><Dial/></Gather></Response>"),
+            Err(TwimlError::InvalidNesting { .. })
+        ));
+        assert!(matches!(
+            VoiceResponse::parse("<Say>Hi</Say>"),
+            Err(TwimlError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn documents_have_a_single_root() {
+        assert_eq!(
+            parse_element("<Foo/><Response><Hangup/></Response>"),
+            Err(TwimlError::Malformed(
+                "<Response> follows the root element <Foo>".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_element("<Response/><Extra/>"),
+            Err(TwimlError::Malformed(
+                "<Extra> follows the root element <Response>".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_element("<Response></Response><Response></Response>"),
+            Err(TwimlError::Malformed(
+                "<Response> follows the root element <Response>".to_string()
+            ))
+        );
+        assert!(parse_element("<?xml version=\"1.0\"?>\n<Response/>\n").is_ok());
+    }
+}
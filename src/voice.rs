@@ -0,0 +1,340 @@
+use crate::client::{send_json, TwilioClient};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum CallStatus {
+    Queued,
+    Initiated,
+    Ringing,
+    InProgress,
+    Completed,
+    Busy,
+    NoAnswer,
+    Canceled,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl CallStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CallStatus::Queued => "queued",
+            CallStatus::Initiated => "initiated",
+            CallStatus::Ringing => "ringing",
+            CallStatus::InProgress => "in-progress",
+            CallStatus::Completed => "completed",
+            CallStatus::Busy => "busy",
+            CallStatus::NoAnswer => "no-answer",
+            CallStatus::Canceled => "canceled",
+            CallStatus::Failed => "failed",
+            CallStatus::Unknown => "unknown",
+        }
+    }
+
+    /// Whether the call has ended and will not change status again.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            CallStatus::Completed
+                | CallStatus::Busy
+                | CallStatus::NoAnswer
+                | CallStatus::Canceled
+                | CallStatus::Failed
+        )
+    }
+}
+
+impl From<&str> for CallStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "queued" => CallStatus::Queued,
+            "initiated" => CallStatus::Initiated,
+            "ringing" => CallStatus::Ringing,
+            "in-progress" => CallStatus::InProgress,
+            "completed" => CallStatus::Completed,
+            "busy" => CallStatus::Busy,
+            "no-answer" => CallStatus::NoAnswer,
+            "canceled" => CallStatus::Canceled,
+            "failed" => CallStatus::Failed,
+            _ => CallStatus::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for CallStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Call progress events that trigger the `StatusCallback`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallStatusEvent {
+    Initiated,
+    Ringing,
+    Answered,
+    Completed,
+}
+
+impl CallStatusEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CallStatusEvent::Initiated => "initiated",
+            CallStatusEvent::Ringing => "ringing",
+            CallStatusEvent::Answered => "answered",
+            CallStatusEvent::Completed => "completed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MachineDetection {
+    /// Return as soon as a human or machine is detected.
+    Enable,
+    /// Wait for the end of the voicemail greeting before returning.
+    DetectMessageEnd,
+}
+
+impl MachineDetection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MachineDetection::Enable => "Enable",
+            MachineDetection::DetectMessageEnd => "DetectMessageEnd",
+        }
+    }
+}
+
+/// What Twilio should do once the call connects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallInstructions {
+    /// Fetch TwiML from a URL, optionally with a specific HTTP method.
+    Url { url: String, method: Option<String> },
+    /// Inline TwiML document.
+    Twiml(String),
+    /// A TwiML App SID whose voice URL handles the call.
+    ApplicationSid(String),
+}
+
+impl CallInstructions {
+    pub fn url(url: &str) -> Self {
+        CallInstructions::Url {
+            url: url.to_string(),
+            method: None,
+        }
+    }
+
+    pub fn twiml(twiml: &str) -> Self {
+        CallInstructions::Twiml(twiml.to_string())
+    }
+
+    fn push_params(&self, params: &mut Vec<(&'static str, String)>) {
+        match self {
+            CallInstructions::Url { url, method } => {
+                params.push(("Url", url.clone()));
+                if let Some(method) = method {
+                    params.push(("Method", method.clone()));
+                }
+            }
+            CallInstructions::Twiml(twiml) => params.push(("Twiml", twiml.clone())),
+            CallInstructions::ApplicationSid(sid) => params.push(("ApplicationSid", sid.clone())),
+        }
+    }
+}
+
+/// Parameters for `TwilioClient::create_call`.
+#[derive(Debug, Clone)]
+pub struct CreateCallRequest {
+    /// Phone number, `client:identity` or `sip:` URI to call.
+    pub to: String,
+    /// Defaults to the client's `from_phone`.
+    pub from: Option<String>,
+    pub instructions: CallInstructions,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<String>,
+    pub status_callback_events: Vec<CallStatusEvent>,
+    pub machine_detection: Option<MachineDetection>,
+    pub record: bool,
+    /// Seconds to let the call ring before giving up.
+    pub timeout: Option<u32>,
+    /// Caller ID shown when calling a `client:` or `sip:` destination.
+    pub caller_id: Option<String>,
+}
+
+impl CreateCallRequest {
+    pub fn new(to: &str, instructions: CallInstructions) -> Self {
+        Self {
+            to: to.to_string(),
+            from: None,
+            instructions,
+            status_callback: None,
+            status_callback_method: None,
+            status_callback_events: Vec::new(),
+            machine_detection: None,
+            record: false,
+            timeout: None,
+            caller_id: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CallResource {
+    pub sid: String,
+    pub account_sid: String,
+    pub to: String,
+    pub from: String,
+    pub status: CallStatus,
+    pub direction: Option<String>,
+    pub parent_call_sid: Option<String>,
+    pub phone_number_sid: Option<String>,
+    pub answered_by: Option<String>,
+    pub caller_name: Option<String>,
+    pub forwarded_from: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub duration: Option<String>,
+    pub price: Option<String>,
+    pub price_unit: Option<String>,
+    pub date_created: Option<String>,
+    pub date_updated: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct CallPage {
+    calls: Vec<CallResource>,
+}
+
+/// Filters for `TwilioClient::list_calls`; unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct CallFilter {
+    pub to: Option<String>,
+    pub from: Option<String>,
+    pub status: Option<CallStatus>,
+    pub parent_call_sid: Option<String>,
+    pub page_size: Option<u32>,
+}
+
+/// Address used in a call's `To`/`From`: SIP and Client identities pass
+/// through, anything else is normalized as a phone number.
+pub(crate) fn call_address(client: &TwilioClient, address: &str) -> Result<String, Box<dyn Error>> {
+    if address.starts_with("client:") || address.starts_with("sip:") {
+        return Ok(address.to_string());
+    }
+    Ok(client.parse_number(address)?.e164())
+}
+
+impl TwilioClient {
+    pub async fn create_call(
+        &self,
+        request: &CreateCallRequest,
+    ) -> Result<CallResource, Box<dyn Error>> {
+        let from = match &request.from {
+            Some(from) => call_address(self, from)?,
+            None => self.from_number()?.e164(),
+        };
+
+        let mut params: Vec<(&str, String)> =
+            vec![("To", call_address(self, &request.to)?), ("From", from)];
+        request.instructions.push_params(&mut params);
+
+        if let Some(callback) = &request.status_callback {
+            params.push(("StatusCallback", callback.clone()));
+        }
+        if let Some(method) = &request.status_callback_method {
+            params.push(("StatusCallbackMethod", method.clone()));
+        }
+        for event in &request.status_callback_events {
+            params.push(("StatusCallbackEvent", event.as_str().to_string()));
+        }
+        if let Some(detection) = request.machine_detection {
+            params.push(("MachineDetection", detection.as_str().to_string()));
+        }
+        if request.record {
+            params.push(("Record", "true".to_string()));
+        }
+        if let Some(timeout) = request.timeout {
+            params.push(("Timeout", timeout.to_string()));
+        }
+        if let Some(caller_id) = &request.caller_id {
+            params.push(("CallerId", caller_id.clone()));
+        }
+
+        send_json(
+            self.request(Method::POST, &self.api_url("Calls.json"))
+                .form(&params),
+        )
+        .await
+    }
+
+    pub async fn fetch_call(&self, call_sid: &str) -> Result<CallResource, Box<dyn Error>> {
+        let url = self.api_url(&format!("Calls/{}.json", call_sid));
+        send_json(self.request(Method::GET, &url)).await
+    }
+
+    pub async fn list_calls(
+        &self,
+        filter: &CallFilter,
+    ) -> Result<Vec<CallResource>, Box<dyn Error>> {
+        let mut query: Vec<(&str, String)> = Vec::new();
+        if let Some(to) = &filter.to {
+            query.push(("To", call_address(self, to)?));
+        }
+        if let Some(from) = &filter.from {
+            query.push(("From", call_address(self, from)?));
+        }
+        if let Some(status) = filter.status {
+            query.push(("Status", status.as_str().to_string()));
+        }
+        if let Some(parent) = &filter.parent_call_sid {
+            query.push(("ParentCallSid", parent.clone()));
+        }
+        if let Some(page_size) = filter.page_size {
+            query.push(("PageSize", page_size.to_string()));
+        }
+
+        let page: CallPage = send_json(
+            self.request(Method::GET, &self.api_url("Calls.json"))
+                .query(&query),
+        )
+        .await?;
+        Ok(page.calls)
+    }
+
+    /// Points an in-progress call at new instructions.
+    pub async fn redirect_call(
+        &self,
+        call_sid: &str,
+        instructions: &CallInstructions,
+    ) -> Result<CallResource, Box<dyn Error>> {
+        let mut params = Vec::new();
+        instructions.push_params(&mut params);
+        self.update_call(call_sid, &params).await
+    }
+
+    /// Ends an in-progress call.
+    pub async fn hangup_call(&self, call_sid: &str) -> Result<CallResource, Box<dyn Error>> {
+        self.update_call(call_sid, &[("Status", "completed".to_string())])
+            .await
+    }
+
+    /// Cancels a call that is still queued or ringing.
+    pub async fn cancel_call(&self, call_sid: &str) -> Result<CallResource, Box<dyn Error>> {
+        self.update_call(call_sid, &[("Status", "canceled".to_string())])
+            .await
+    }
+
+    async fn update_call(
+        &self,
+        call_sid: &str,
+        params: &[(&str, String)],
+    ) -> Result<CallResource, Box<dyn Error>> {
+        let url = self.api_url(&format!("Calls/{}.json", call_sid));
+        send_json(self.request(Method::POST, &url).form(params)).await
+    }
+}
@@ -0,0 +1,716 @@
+// voice_webhook.rs
+
+use crate::conference::ConferenceEvent;
+use crate::twiml::VoiceResponse;
+use crate::voice::CallStatus;
+use crate::webhook::is_signed_by_twilio;
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Async handler for a voice webhook, returning the TwiML to send back.
+pub type VoiceHandler<T> =
+    Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = VoiceResponse>>> + Send + Sync>;
+
+fn field(data: &HashMap<String, String>, key: &str) -> String {
+    data.get(key).cloned().unwrap_or_default()
+}
+
+fn optional(data: &HashMap<String, String>, key: &str) -> Option<String> {
+    data.get(key).filter(|value| !value.is_empty()).cloned()
+}
+
+fn flag(data: &HashMap<String, String>, key: &str) -> Option<bool> {
+    data.get(key).map(|value| value == "true")
+}
+
+fn optional_number<T: std::str::FromStr>(data: &HashMap<String, String>, key: &str) -> Option<T> {
+    data.get(key).and_then(|value| value.parse().ok())
+}
+
+/// Caller location Twilio derives from the `From`/`To` numbers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallGeo {
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub zip: Option<String>,
+    pub country: Option<String>,
+}
+
+impl CallGeo {
+    fn from_params(data: &HashMap<String, String>, prefix: &str) -> Self {
+        Self {
+            city: optional(data, &format!("{}City", prefix)),
+            state: optional(data, &format!("{}State", prefix)),
+            zip: optional(data, &format!("{}Zip", prefix)),
+            country: optional(data, &format!("{}Country", prefix)),
+        }
+    }
+}
+
+/// Parameters Twilio sends with every voice request.
+#[derive(Debug, Clone)]
+pub struct IncomingCall {
+    pub call_sid: String,
+    pub account_sid: String,
+    pub from: String,
+    pub to: String,
+    pub call_status: CallStatus,
+    /// `inbound`, `outbound-api` or `outbound-dial`.
+    pub direction: String,
+    pub api_version: Option<String>,
+    pub forwarded_from: Option<String>,
+    pub caller_name: Option<String>,
+    pub parent_call_sid: Option<String>,
+    pub answered_by: Option<String>,
+    pub from_geo: CallGeo,
+    pub to_geo: CallGeo,
+}
+
+impl IncomingCall {
+    pub fn from_params(data: &HashMap<String, String>) -> Self {
+        Self {
+            call_sid: field(data, "CallSid"),
+            account_sid: field(data, "AccountSid"),
+            from: field(data, "From"),
+            to: field(data, "To"),
+            call_status: CallStatus::from(field(data, "CallStatus").as_str()),
+            direction: field(data, "Direction"),
+            api_version: optional(data, "ApiVersion"),
+            forwarded_from: optional(data, "ForwardedFrom"),
+            caller_name: optional(data, "CallerName"),
+            parent_call_sid: optional(data, "ParentCallSid"),
+            answered_by: optional(data, "AnsweredBy"),
+            from_geo: CallGeo::from_params(data, "From"),
+            to_geo: CallGeo::from_params(data, "To"),
+        }
+    }
+}
+
+/// Result of a `<Gather>`, posted to its `action` URL.
+#[derive(Debug, Clone)]
+pub struct GatherResult {
+    pub call: IncomingCall,
+    pub digits: Option<String>,
+    pub speech_result: Option<String>,
+    pub confidence: Option<f32>,
+    pub finished_on_key: Option<String>,
+}
+
+impl GatherResult {
+    pub fn from_params(data: &HashMap<String, String>) -> Self {
+        Self {
+            call: IncomingCall::from_params(data),
+            digits: optional(data, "Digits"),
+            speech_result: optional(data, "SpeechResult"),
+            confidence: optional_number(data, "Confidence"),
+            finished_on_key: optional(data, "FinishedOnKey"),
+        }
+    }
+}
+
+/// Call progress event posted to a call's `StatusCallback`.
+#[derive(Debug, Clone)]
+pub struct CallStatusCallback {
+    pub call: IncomingCall,
+    /// Seconds the call lasted; only present once it has completed.
+    pub call_duration: Option<u32>,
+    pub timestamp: Option<String>,
+    pub sequence_number: Option<u32>,
+    pub callback_source: Option<String>,
+    pub sip_response_code: Option<u32>,
+}
+
+impl CallStatusCallback {
+    pub fn from_params(data: &HashMap<String, String>) -> Self {
+        Self {
+            call: IncomingCall::from_params(data),
+            call_duration: optional_number(data, "CallDuration"),
+            timestamp: optional(data, "Timestamp"),
+            sequence_number: optional_number(data, "SequenceNumber"),
+            callback_source: optional(data, "CallbackSource"),
+            sip_response_code: optional_number(data, "SipResponseCode"),
+        }
+    }
+}
+
+/// Posted to `recordingStatusCallback` as a recording progresses.
+#[derive(Debug, Clone)]
+pub struct RecordingStatusCallback {
+    pub account_sid: String,
+    pub call_sid: String,
+    pub recording_sid: String,
+    pub recording_url: String,
+    /// `in-progress`, `completed`, `absent` or `failed`.
+    pub recording_status: String,
+    pub recording_duration: Option<u32>,
+    pub recording_channels: Option<u32>,
+    pub recording_source: Option<String>,
+    pub recording_start_time: Option<String>,
+    pub error_code: Option<String>,
+}
+
+impl RecordingStatusCallback {
+    pub fn from_params(data: &HashMap<String, String>) -> Self {
+        Self {
+            account_sid: field(data, "AccountSid"),
+            call_sid: field(data, "CallSid"),
+            recording_sid: field(data, "RecordingSid"),
+            recording_url: field(data, "RecordingUrl"),
+            recording_status: field(data, "RecordingStatus"),
+            recording_duration: optional_number(data, "RecordingDuration"),
+            recording_channels: optional_number(data, "RecordingChannels"),
+            recording_source: optional(data, "RecordingSource"),
+            recording_start_time: optional(data, "RecordingStartTime"),
+            error_code: optional(data, "ErrorCode").filter(|code| code != "0"),
+        }
+    }
+}
+
+/// Posted to a conference's `statusCallback` as the conference and its
+/// participants change.
+#[derive(Debug, Clone)]
+pub struct ConferenceStatusCallback {
+    pub account_sid: String,
+    pub conference_sid: String,
+    pub friendly_name: String,
+    pub event: ConferenceEvent,
+    pub sequence_number: Option<u32>,
+    pub timestamp: Option<String>,
+    /// Participant the event is about; absent for `conference-start`/`-end`.
+    pub call_sid: Option<String>,
+    pub participant_label: Option<String>,
+    pub muted: Option<bool>,
+    pub hold: Option<bool>,
+    pub coaching: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    pub start_conference_on_enter: Option<bool>,
+    pub reason_conference_ended: Option<String>,
+    pub call_sid_ending_conference: Option<String>,
+    pub reason: Option<String>,
+}
+
+impl ConferenceStatusCallback {
+    pub fn from_params(data: &HashMap<String, String>) -> Self {
+        Self {
+            account_sid: field(data, "AccountSid"),
+            conference_sid: field(data, "ConferenceSid"),
+            friendly_name: field(data, "FriendlyName"),
+            event: ConferenceEvent::from(field(data, "StatusCallbackEvent").as_str()),
+            sequence_number: optional_number(data, "SequenceNumber"),
+            timestamp: optional(data, "Timestamp"),
+            call_sid: optional(data, "CallSid"),
+            participant_label: optional(data, "ParticipantLabel"),
+            muted: flag(data, "Muted"),
+            hold: flag(data, "Hold"),
+            coaching: flag(data, "Coaching"),
+            end_conference_on_exit: flag(data, "EndConferenceOnExit"),
+            start_conference_on_enter: flag(data, "StartConferenceOnEnter"),
+            reason_conference_ended: optional(data, "ReasonConferenceEnded"),
+            call_sid_ending_conference: optional(data, "CallSidEndingConference"),
+            reason: optional(data, "Reason"),
+        }
+    }
+}
+
+/// Posted to an `<Enqueue waitUrl>` while the caller waits; the returned
+/// TwiML (hold music, `<Say>`, `<Leave>`, ...) is played in a loop.
+#[derive(Debug, Clone)]
+pub struct QueueWait {
+    pub call: IncomingCall,
+    pub queue_sid: String,
+    /// 1-based position of this caller.
+    pub queue_position: Option<u32>,
+    /// Seconds this caller has waited.
+    pub queue_time: Option<u32>,
+    /// Average seconds callers in the queue have waited.
+    pub avg_queue_time: Option<u32>,
+    pub current_queue_size: Option<u32>,
+    pub max_queue_size: Option<u32>,
+}
+
+impl QueueWait {
+    pub fn from_params(data: &HashMap<String, String>) -> Self {
+        Self {
+            call: IncomingCall::from_params(data),
+            queue_sid: field(data, "QueueSid"),
+            queue_position: optional_number(data, "QueuePosition"),
+            queue_time: optional_number(data, "QueueTime"),
+            avg_queue_time: optional_number(data, "AvgQueueTime"),
+            current_queue_size: optional_number(data, "CurrentQueueSize"),
+            max_queue_size: optional_number(data, "MaxQueueSize"),
+        }
+    }
+}
+
+/// Why a caller left the queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueueResult {
+    Bridged,
+    BridgingInProcess,
+    QueueFull,
+    Redirected,
+    Hangup,
+    Leave,
+    Error,
+    SystemError,
+    Unknown,
+}
+
+impl From<&str> for QueueResult {
+    fn from(result: &str) -> Self {
+        match result {
+            "bridged" => QueueResult::Bridged,
+            "bridging-in-process" => QueueResult::BridgingInProcess,
+            "queue-full" => QueueResult::QueueFull,
+            "redirected" => QueueResult::Redirected,
+            "hangup" => QueueResult::Hangup,
+            "leave" => QueueResult::Leave,
+            "error" => QueueResult::Error,
+            "system-error" => QueueResult::SystemError,
+            _ => QueueResult::Unknown,
+        }
+    }
+}
+
+/// Posted to an `<Enqueue action>` when the caller leaves the queue.
+#[derive(Debug, Clone)]
+pub struct EnqueueResult {
+    pub call: IncomingCall,
+    pub queue_result: QueueResult,
+    pub queue_sid: Option<String>,
+    /// Seconds the caller spent in the queue.
+    pub queue_time: Option<u32>,
+}
+
+impl EnqueueResult {
+    pub fn from_params(data: &HashMap<String, String>) -> Self {
+        Self {
+            call: IncomingCall::from_params(data),
+            queue_result: QueueResult::from(field(data, "QueueResult").as_str()),
+            queue_sid: optional(data, "QueueSid"),
+            queue_time: optional_number(data, "QueueTime"),
+        }
+    }
+}
+
+fn boxed<T, F, Fut>(handler: F) -> VoiceHandler<T>
+where
+    F: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = VoiceResponse> + 'static,
+{
+    Arc::new(move |event| Box::pin(handler(event)))
+}
+
+/// Handlers for each voice webhook; unset handlers answer with an empty
+/// `<Response/>`.
+#[derive(Clone, Default)]
+pub struct VoiceWebhookHandlers {
+    pub incoming_call: Option<VoiceHandler<IncomingCall>>,
+    pub gather: Option<VoiceHandler<GatherResult>>,
+    pub call_status: Option<VoiceHandler<CallStatusCallback>>,
+    pub recording_status: Option<VoiceHandler<RecordingStatusCallback>>,
+    pub conference_status: Option<VoiceHandler<ConferenceStatusCallback>>,
+    pub queue_wait: Option<VoiceHandler<QueueWait>>,
+    pub enqueue_result: Option<VoiceHandler<EnqueueResult>>,
+}
+
+impl VoiceWebhookHandlers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_incoming_call<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(IncomingCall) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = VoiceResponse> + 'static,
+    {
+        self.incoming_call = Some(boxed(handler));
+        self
+    }
+
+    pub fn on_gather<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(GatherResult) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = VoiceResponse> + 'static,
+    {
+        self.gather = Some(boxed(handler));
+        self
+    }
+
+    pub fn on_call_status<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(CallStatusCallback) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = VoiceResponse> + 'static,
+    {
+        self.call_status = Some(boxed(handler));
+        self
+    }
+
+    pub fn on_recording_status<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(RecordingStatusCallback) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = VoiceResponse> + 'static,
+    {
+        self.recording_status = Some(boxed(handler));
+        self
+    }
+
+    pub fn on_conference_status<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(ConferenceStatusCallback) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = VoiceResponse> + 'static,
+    {
+        self.conference_status = Some(boxed(handler));
+        self
+    }
+
+    pub fn on_queue_wait<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(QueueWait) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = VoiceResponse> + 'static,
+    {
+        self.queue_wait = Some(boxed(handler));
+        self
+    }
+
+    pub fn on_enqueue_result<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(EnqueueResult) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = VoiceResponse> + 'static,
+    {
+        self.enqueue_result = Some(boxed(handler));
+        self
+    }
+}
+
+struct VoiceWebhookConfig {
+    base_url: String,
+    handlers: VoiceWebhookHandlers,
+}
+
+/// Rebuilds the public URL Twilio signed from the configured base URL and
+/// the route (plus any query string, e.g. from a `<Gather action>`).
+fn signed_url(base_url: &str, suffix: &str, req: &HttpRequest) -> String {
+    let mut url = format!("{}{}", base_url.trim_end_matches('/'), suffix);
+    if !req.query_string().is_empty() {
+        url.push('?');
+        url.push_str(req.query_string());
+    }
+    url
+}
+
+pub(crate) fn twiml_response(response: &VoiceResponse) -> HttpResponse {
+    match response.to_xml() {
+        Ok(xml) => HttpResponse::Ok().content_type("text/xml").body(xml),
+        Err(e) => HttpResponse::InternalServerError().body(format!("Invalid TwiML: {}", e)),
+    }
+}
+
+async fn dispatch<T>(
+    req: &HttpRequest,
+    data: &HashMap<String, String>,
+    config: &VoiceWebhookConfig,
+    suffix: &str,
+    handler: Option<&VoiceHandler<T>>,
+    parse: fn(&HashMap<String, String>) -> T,
+) -> HttpResponse {
+    if !is_signed_by_twilio(req, &signed_url(&config.base_url, suffix, req), data) {
+        return HttpResponse::Unauthorized().body("Invalid Twilio signature.");
+    }
+    let response = match handler {
+        Some(handler) => handler(parse(data)).await,
+        None => VoiceResponse::new(),
+    };
+    twiml_response(&response)
+}
+
+async fn incoming_call_handler(
+    req: HttpRequest,
+    form: web::Form<HashMap<String, String>>,
+    config: web::Data<VoiceWebhookConfig>,
+) -> HttpResponse {
+    let handler = config.handlers.incoming_call.as_ref();
+    dispatch(&req, &form, &config, "", handler, IncomingCall::from_params).await
+}
+
+async fn gather_handler(
+    req: HttpRequest,
+    form: web::Form<HashMap<String, String>>,
+    config: web::Data<VoiceWebhookConfig>,
+) -> HttpResponse {
+    let handler = config.handlers.gather.as_ref();
+    dispatch(
+        &req,
+        &form,
+        &config,
+        "/gather",
+        handler,
+        GatherResult::from_params,
+    )
+    .await
+}
+
+async fn call_status_handler(
+    req: HttpRequest,
+    form: web::Form<HashMap<String, String>>,
+    config: web::Data<VoiceWebhookConfig>,
+) -> HttpResponse {
+    let handler = config.handlers.call_status.as_ref();
+    dispatch(
+        &req,
+        &form,
+        &config,
+        "/status",
+        handler,
+        CallStatusCallback::from_params,
+    )
+    .await
+}
+
+async fn recording_status_handler(
+    req: HttpRequest,
+    form: web::Form<HashMap<String, String>>,
+    config: web::Data<VoiceWebhookConfig>,
+) -> HttpResponse {
+    let handler = config.handlers.recording_status.as_ref();
+    dispatch(
+        &req,
+        &form,
+        &config,
+        "/recording",
+        handler,
+        RecordingStatusCallback::from_params,
+    )
+    .await
+}
+
+async fn conference_status_handler(
+    req: HttpRequest,
+    form: web::Form<HashMap<String, String>>,
+    config: web::Data<VoiceWebhookConfig>,
+) -> HttpResponse {
+    let handler = config.handlers.conference_status.as_ref();
+    dispatch(
+        &req,
+        &form,
+        &config,
+        "/conference",
+        handler,
+        ConferenceStatusCallback::from_params,
+    )
+    .await
+}
+
+async fn queue_wait_handler(
+    req: HttpRequest,
+    form: web::Form<HashMap<String, String>>,
+    config: web::Data<VoiceWebhookConfig>,
+) -> HttpResponse {
+    let handler = config.handlers.queue_wait.as_ref();
+    dispatch(
+        &req,
+        &form,
+        &config,
+        "/queue/wait",
+        handler,
+        QueueWait::from_params,
+    )
+    .await
+}
+
+async fn enqueue_result_handler(
+    req: HttpRequest,
+    form: web::Form<HashMap<String, String>>,
+    config: web::Data<VoiceWebhookConfig>,
+) -> HttpResponse {
+    let handler = config.handlers.enqueue_result.as_ref();
+    dispatch(
+        &req,
+        &form,
+        &config,
+        "/queue/action",
+        handler,
+        EnqueueResult::from_params,
+    )
+    .await
+}
+
+/// Mounts the voice webhooks under `/twilio/voice`:
+///
+/// * `POST /twilio/voice` – incoming calls
+/// * `POST /twilio/voice/gather` – `<Gather>` results
+/// * `POST /twilio/voice/status` – call status callbacks
+/// * `POST /twilio/voice/recording` – recording status callbacks
+/// * `POST /twilio/voice/conference` – conference status callbacks
+/// * `POST /twilio/voice/queue/wait` – `<Enqueue waitUrl>` requests
+/// * `POST /twilio/voice/queue/action` – `<Enqueue action>` results
+///
+/// `base_url` is the public URL of the scope (e.g.
+/// `https://myapp.com/twilio/voice`) and is used to validate signatures.
+pub fn build_voice_webhook_scope(base_url: &str, handlers: VoiceWebhookHandlers) -> Scope {
+    web::scope("/twilio/voice")
+        .app_data(web::Data::new(VoiceWebhookConfig {
+            base_url: base_url.to_string(),
+            handlers,
+        }))
+        .route("", web::post().to(incoming_call_handler))
+        .route("/gather", web::post().to(gather_handler))
+        .route("/status", web::post().to(call_status_handler))
+        .route("/recording", web::post().to(recording_status_handler))
+        .route("/conference", web::post().to(conference_status_handler))
+        .route("/queue/wait", web::post().to(queue_wait_handler))
+        .route("/queue/action", web::post().to(enqueue_result_handler))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::{call_and_read_body, call_service, init_service, TestRequest};
+    use actix_web::App;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use hmac::{Hmac, Mac};
+    use sha1::Sha1;
+
+    const BASE_URL: &str = "https://example.com/twilio/voice";
+    const AUTH_TOKEN: &str = "voice_webhook_test_token";
+
+    fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn sign(url: &str, data: &HashMap<String, String>) -> String {
+        let mut keys: Vec<&String> = data.keys().collect();
+        keys.sort();
+        let mut message = url.to_string();
+        for key in keys {
+            message.push_str(key);
+            message.push_str(&data[key]);
+        }
+        let mut mac = Hmac::<Sha1>::new_from_slice(AUTH_TOKEN.as_bytes()).unwrap();
+        mac.update(message.as_bytes());
+        STANDARD.encode(mac.finalize().into_bytes())
+    }
+
+    const CALL: &[(&str, &str)] = &[
+        ("CallSid", "CA123"),
+        ("AccountSid", "AC123"),
+        ("From", "+14155550100"),
+        ("To", "+14155550199"),
+        ("CallStatus", "in-progress"),
+        ("Direction", "inbound"),
+        ("FromCity", "SAN FRANCISCO"),
+        ("FromCountry", "US"),
+        ("ToZip", ""),
+    ];
+
+    #[test]
+    fn parses_incoming_calls() {
+        let call = IncomingCall::from_params(&params(CALL));
+        assert_eq!(call.call_sid, "CA123");
+        assert_eq!(call.call_status, CallStatus::InProgress);
+        assert_eq!(call.direction, "inbound");
+        assert_eq!(call.from_geo.city.as_deref(), Some("SAN FRANCISCO"));
+        assert_eq!(call.from_geo.country.as_deref(), Some("US"));
+        assert_eq!(call.to_geo, CallGeo::default());
+        assert_eq!(call.forwarded_from, None);
+
+        let empty = IncomingCall::from_params(&HashMap::new());
+        assert_eq!(empty.call_sid, "");
+        assert_eq!(empty.call_status, CallStatus::Unknown);
+        assert_eq!(empty.api_version, None);
+    }
+
+    #[test]
+    fn parses_gather_results() {
+        let mut data = params(CALL);
+        data.extend(params(&[
+            ("Digits", "1"),
+            ("SpeechResult", "sales please"),
+            ("Confidence", "0.92"),
+            ("FinishedOnKey", ""),
+        ]));
+        let gather = GatherResult::from_params(&data);
+        assert_eq!(gather.call.call_sid, "CA123");
+        assert_eq!(gather.digits.as_deref(), Some("1"));
+        assert_eq!(gather.speech_result.as_deref(), Some("sales please"));
+        assert_eq!(gather.confidence, Some(0.92));
+        assert_eq!(gather.finished_on_key, None);
+
+        let timed_out = GatherResult::from_params(&params(&[("Confidence", "high")]));
+        assert_eq!(timed_out.digits, None);
+        assert_eq!(timed_out.speech_result, None);
+        assert_eq!(timed_out.confidence, None);
+    }
+
+    #[test]
+    fn parses_recording_callbacks() {
+        let recording = RecordingStatusCallback::from_params(&params(&[
+            ("AccountSid", "AC123"),
+            ("CallSid", "CA123"),
+            ("RecordingSid", "RE123"),
+            ("RecordingUrl", "https://api.twilio.com/recordings/RE123"),
+            ("RecordingStatus", "completed"),
+            ("RecordingDuration", "42"),
+            ("RecordingChannels", "2"),
+            ("ErrorCode", "0"),
+        ]));
+        assert_eq!(recording.recording_sid, "RE123");
+        assert_eq!(recording.recording_status, "completed");
+        assert_eq!(recording.recording_duration, Some(42));
+        assert_eq!(recording.recording_channels, Some(2));
+        assert_eq!(recording.recording_source, None);
+        assert_eq!(recording.error_code, None);
+
+        let failed = RecordingStatusCallback::from_params(&params(&[
+            ("RecordingStatus", "failed"),
+            ("RecordingDuration", "-1"),
+            ("ErrorCode", "13617"),
+        ]));
+        assert_eq!(failed.recording_duration, None);
+        assert_eq!(failed.error_code.as_deref(), Some("13617"));
+    }
+
+    #[actix_web::test]
+    async fn dispatch_checks_the_signature() {
+        std::env::set_var("TWILIO_AUTH_TOKEN", AUTH_TOKEN);
+        let handlers = VoiceWebhookHandlers::new().on_gather(|gather: GatherResult| async move {
+            let digits = gather.digits.unwrap_or_default();
+            VoiceResponse::new().say(crate::twiml::Say::new(&format!("You pressed {}", digits)))
+        });
+        let app =
+            init_service(App::new().service(build_voice_webhook_scope(BASE_URL, handlers))).await;
+        let data = params(&[("CallSid", "CA123"), ("Digits", "7")]);
+        let url = format!("{}/gather", BASE_URL);
+
+        for signature in [None, Some("bogus".to_string()), Some(sign(BASE_URL, &data))] {
+            let mut request = TestRequest::post()
+                .uri("/twilio/voice/gather")
+                .set_form(&data);
+            if let Some(signature) = signature {
+                request = request.insert_header(("X-Twilio-Signature", signature));
+            }
+            let response = call_service(&app, request.to_request()).await;
+            assert_eq!(response.status(), 401);
+        }
+
+        let request = TestRequest::post()
+            .uri("/twilio/voice/gather")
+            .insert_header(("X-Twilio-Signature", sign(&url, &data)))
+            .set_form(&data)
+            .to_request();
+        let body = call_and_read_body(&app, request).await;
+        assert!(
+            String::from_utf8_lossy(&body)
+                .ends_with("<Response><Say>You pressed 7</Say></Response>"),
+            "{:?}",
+            body
+        );
+    }
+}
@@ -1,12 +1,14 @@
 // webhook.rs
 
 use crate::client::TwilioClient;
-use crate::signature::validate_twilio_signature;
-use crate::whatsapp::send_whatsapp_text;
+use crate::phone::{strip_address_prefix, PhoneNumber, PhoneNumberError};
+use crate::signature::validate_twilio_signature_any;
+use crate::whatsapp::{send_whatsapp_text, InboundOrder, Location};
+use crate::whatsapp_session::SessionTracker;
+use crate::AutoReplyHandler;
 use actix_web::{post, web, HttpRequest, HttpResponse, Responder, Scope};
 use serde::Deserialize;
 use std::collections::HashMap;
-use std::sync::Arc;
 
 #[derive(Debug, Deserialize)]
 pub struct IncomingWhatsAppMessage {
@@ -22,6 +24,139 @@ pub struct IncomingWhatsAppMessage {
     pub profile_name: Option<String>,
     #[serde(rename = "WaId")]
     pub wa_id: Option<String>,
+    #[serde(rename = "Latitude")]
+    pub latitude: Option<f64>,
+    #[serde(rename = "Longitude")]
+    pub longitude: Option<f64>,
+    #[serde(rename = "Address")]
+    pub address: Option<String>,
+    #[serde(rename = "Label")]
+    pub label: Option<String>,
+    /// Attachments, from `MediaUrl{N}`/`MediaContentType{N}`.
+    #[serde(skip)]
+    pub media: Vec<InboundMedia>,
+    /// `text`, `location`, `order`... as reported by Twilio.
+    #[serde(rename = "MessageType")]
+    pub message_type: Option<String>,
+    /// The raw JSON `Order` parameter, sent when the customer submits a
+    /// cart from a catalog. Read it with `order()`.
+    #[serde(rename = "Order")]
+    pub order_json: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboundMedia {
+    pub url: String,
+    pub content_type: String,
+}
+
+impl InboundMedia {
+    /// Whether this is a shared contact card.
+    pub fn is_vcard(&self) -> bool {
+        matches!(
+            self.content_type.as_str(),
+            "text/vcard" | "text/x-vcard" | "text/directory"
+        )
+    }
+}
+
+fn parsed<T: std::str::FromStr>(data: &HashMap<String, String>, key: &str) -> Option<T> {
+    data.get(key).and_then(|value| value.parse().ok())
+}
+
+impl IncomingWhatsAppMessage {
+    pub fn from_params(data: &HashMap<String, String>) -> Self {
+        let optional = |key: &str| data.get(key).filter(|value| !value.is_empty()).cloned();
+        let media_count: usize = parsed(data, "NumMedia").unwrap_or(0);
+        Self {
+            from: data.get("From").cloned().unwrap_or_default(),
+            to: data.get("To").cloned().unwrap_or_default(),
+            body: data.get("Body").cloned().unwrap_or_default(),
+            message_sid: data.get("MessageSid").cloned().unwrap_or_default(),
+            profile_name: data.get("ProfileName").cloned(),
+            wa_id: data.get("WaId").cloned(),
+            latitude: parsed(data, "Latitude"),
+            longitude: parsed(data, "Longitude"),
+            address: optional("Address"),
+            label: optional("Label"),
+            message_type: optional("MessageType"),
+            order_json: optional("Order"),
+            media: (0..media_count)
+                .filter_map(|index| {
+                    Some(InboundMedia {
+                        url: optional(&format!("MediaUrl{}", index))?,
+                        content_type: optional(&format!("MediaContentType{}", index))
+                            .unwrap_or_default(),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// The submitted cart, if this message is one. A malformed `Order` is
+    /// reported as an error rather than dropped; `order_json` keeps the raw
+    /// value either way.
+    pub fn order(&self) -> Option<Result<InboundOrder, serde_json::Error>> {
+        self.order_json.as_deref().map(InboundOrder::from_json)
+    }
+
+    /// The location the customer shared, if this message is one.
+    pub fn location(&self) -> Option<Location> {
+        Some(Location {
+            latitude: self.latitude?,
+            longitude: self.longitude?,
+            label: self.label.clone(),
+            address: self.address.clone(),
+        })
+    }
+
+    /// URLs of contact cards (vCards) the customer shared.
+    pub fn contact_card_urls(&self) -> Vec<&str> {
+        self.media
+            .iter()
+            .filter(|media| media.is_vcard())
+            .map(|media| media.url.as_str())
+            .collect()
+    }
+
+    /// The sender's number without the `whatsapp:` prefix.
+    pub fn sender(&self) -> Result<PhoneNumber, PhoneNumberError> {
+        PhoneNumber::parse(&self.from, None)
+    }
+
+    /// The business number the message was sent to.
+    pub fn recipient(&self) -> Result<PhoneNumber, PhoneNumberError> {
+        PhoneNumber::parse(&self.to, None)
+    }
+}
+
+/// Checks the `X-Twilio-Signature` header of a webhook request against the
+/// URL Twilio was configured to call.
+pub(crate) fn is_signed_by_twilio(
+    req: &HttpRequest,
+    url: &str,
+    data: &HashMap<String, String>,
+) -> bool {
+    let signature = req
+        .headers()
+        .get("X-Twilio-Signature")
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or("");
+
+    // During auth token rotation the secondary token may already be signing
+    // requests. Unset tokens are skipped: with an API key the auth token is
+    // optional, and an empty key would accept forged signatures.
+    let tokens: Vec<String> = ["TWILIO_AUTH_TOKEN", "TWILIO_SECONDARY_AUTH_TOKEN"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .filter(|token| !token.is_empty())
+        .collect();
+    if tokens.is_empty() {
+        return false;
+    }
+
+    let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+    validate_twilio_signature_any(url, data, signature, &tokens)
 }
 
 /// Internal handler that allows an optional auto-reply handler
@@ -30,36 +165,30 @@ async fn internal_webhook_handler(
     req: HttpRequest,
     form: web::Form<HashMap<String, String>>,
     base_url: web::Data<String>,
-    auto_reply_handler: web::Data<Option<Arc<dyn Fn(String, String) -> String + Send + Sync>>>,
+    auto_reply_handler: web::Data<Option<AutoReplyHandler>>,
+    sessions: web::Data<Option<SessionTracker>>,
 ) -> impl Responder {
     let data = form.into_inner();
-    let signature = req
-        .headers()
-        .get("X-Twilio-Signature")
-        .and_then(|h| h.to_str().ok())
-        .unwrap_or("");
-
-    let auth_token = std::env::var("TWILIO_AUTH_TOKEN").unwrap_or_default();
-
-    if !validate_twilio_signature(base_url.get_ref(), &data, signature, &auth_token) {
+    if !is_signed_by_twilio(&req, base_url.get_ref(), &data) {
         return HttpResponse::Unauthorized().body("Invalid Twilio signature.");
     }
 
-    let msg = IncomingWhatsAppMessage {
-        from: data.get("From").cloned().unwrap_or_default(),
-        to: data.get("To").cloned().unwrap_or_default(),
-        body: data.get("Body").cloned().unwrap_or_default(),
-        message_sid: data.get("MessageSid").cloned().unwrap_or_default(),
-        profile_name: data.get("ProfileName").cloned(),
-        wa_id: data.get("WaId").cloned(),
-    };
+    let msg = IncomingWhatsAppMessage::from_params(&data);
 
     println!("✅ Verified Incoming Message: {:?}", msg);
 
+    if let Some(sessions) = sessions.get_ref() {
+        sessions.record_message(&msg);
+    }
+
     if let Some(handler) = auto_reply_handler.get_ref().as_ref() {
         let reply = (handler)(msg.from.clone(), msg.body.clone());
         let client = TwilioClient::new();
-        let _ = send_whatsapp_text(&client, &msg.from.replace("whatsapp:", ""), &reply).await;
+        let to = msg
+            .sender()
+            .map(|number| number.e164())
+            .unwrap_or_else(|_| strip_address_prefix(&msg.from).to_string());
+        let _ = send_whatsapp_text(&client, &to, &reply).await;
     }
 
     HttpResponse::Ok().finish()
@@ -68,10 +197,54 @@ async fn internal_webhook_handler(
 /// Public function to mount the WhatsApp webhook scope
 pub fn build_whatsapp_webhook_scope(
     base_url: &str,
-    auto_reply_handler: Option<Arc<dyn Fn(String, String) -> String + Send + Sync>>,
+    auto_reply_handler: Option<AutoReplyHandler>,
+) -> Scope {
+    whatsapp_webhook_scope(base_url, auto_reply_handler, None)
+}
+
+/// Like `build_whatsapp_webhook_scope`, also recording every inbound
+/// message in `sessions` so the 24-hour window can be checked before sending.
+pub fn build_whatsapp_webhook_scope_with_sessions(
+    base_url: &str,
+    auto_reply_handler: Option<AutoReplyHandler>,
+    sessions: SessionTracker,
+) -> Scope {
+    whatsapp_webhook_scope(base_url, auto_reply_handler, Some(sessions))
+}
+
+fn whatsapp_webhook_scope(
+    base_url: &str,
+    auto_reply_handler: Option<AutoReplyHandler>,
+    sessions: Option<SessionTracker>,
 ) -> Scope {
     web::scope("/twilio/whatsapp")
         .app_data(web::Data::new(base_url.to_string()))
         .app_data(web::Data::new(auto_reply_handler))
+        .app_data(web::Data::new(sessions))
         .service(internal_webhook_handler)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_orders_are_kept_and_reported() {
+        let params: HashMap<String, String> = [
+            ("MessageType", "order"),
+            ("Order", r#"{"catalog_id":"123","product_items":"oops"}"#),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+        let message = IncomingWhatsAppMessage::from_params(&params);
+        assert_eq!(
+            message.order_json.as_deref(),
+            params.get("Order").map(String::as_str)
+        );
+        assert!(matches!(message.order(), Some(Err(_))));
+
+        let message = IncomingWhatsAppMessage::from_params(&HashMap::new());
+        assert!(message.order().is_none());
+    }
+}
@@ -1,37 +1,126 @@
 use crate::client::TwilioClient;
-use reqwest::Client;
-use serde::{Deserialize, Deserializer, Serialize};
+use crate::content::{
+    CallToAction, CallToActionContent, CardAction, CardContent, CatalogContent, CatalogItem,
+    ContentTypes, ContentVariables, ListItem, ListPickerContent, QuickReplyAction,
+    QuickReplyContent, TextContent, FALLBACK_LANGUAGE,
+};
+use crate::decimal::Decimal;
+use crate::interactive::{
+    validate_buttons, validate_catalog, validate_list, validate_product_list, validate_text,
+    InteractiveError,
+};
+use crate::messaging::{Address, OutboundMessage};
+use serde::{Deserialize, Serialize};
 use std::error::Error;
+use std::fmt;
+use std::future::Future;
+
+pub use crate::messaging::TwilioMessageResponse;
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InteractiveButton {
+    /// Quick reply; `id` comes back in the webhook's `ButtonPayload`.
+    Reply {
+        id: String,
+        title: String,
+    },
+    Url {
+        title: String,
+        url: String,
+    },
+    PhoneNumber {
+        title: String,
+        phone: String,
+    },
+    /// Copies `code` (a coupon or one-time passcode) to the clipboard.
+    CopyCode {
+        title: String,
+        code: String,
+    },
+}
+
+impl InteractiveButton {
+    pub fn reply(id: &str, title: &str) -> Self {
+        InteractiveButton::Reply {
+            id: id.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    pub fn url(title: &str, url: &str) -> Self {
+        InteractiveButton::Url {
+            title: title.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    pub fn phone_number(title: &str, phone: &str) -> Self {
+        InteractiveButton::PhoneNumber {
+            title: title.to_string(),
+            phone: phone.to_string(),
+        }
+    }
+
+    pub fn copy_code(title: &str, code: &str) -> Self {
+        InteractiveButton::CopyCode {
+            title: title.to_string(),
+            code: code.to_string(),
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        match self {
+            InteractiveButton::Reply { title, .. }
+            | InteractiveButton::Url { title, .. }
+            | InteractiveButton::PhoneNumber { title, .. }
+            | InteractiveButton::CopyCode { title, .. } => title,
+        }
+    }
+
+    pub fn is_reply(&self) -> bool {
+        matches!(self, InteractiveButton::Reply { .. })
+    }
+}
 
-#[derive(Deserialize, Debug)]
-pub struct TwilioMessageResponse {
-    pub sid: String,
-    pub status: String,
-    pub to: String,
-    pub from: String,
-    pub body: Option<String>,
-    pub account_sid: String,
-    pub api_version: String,
-    pub date_created: Option<String>,
-    pub date_sent: Option<String>,
-    pub date_updated: Option<String>,
-    pub direction: Option<String>,
-    pub error_code: Option<String>,
-    pub error_message: Option<String>,
-    pub messaging_service_sid: Option<String>,
-    #[serde(deserialize_with = "string_or_int")]
-    pub num_media: String,
-    #[serde(deserialize_with = "string_or_int")]
-    pub num_segments: String,
-    pub price: Option<String>,
-    pub price_unit: Option<String>,
-    pub uri: Option<String>,
+/// Header of a button message. Media headers take a public URL.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum InteractiveHeader {
+    Text(String),
+    Image(String),
+    Video(String),
+    Document(String),
 }
 
-#[derive(Serialize, Debug)]
-pub struct InteractiveButton {
-    pub id: String,
-    pub title: String,
+impl InteractiveHeader {
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            InteractiveHeader::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn media_url(&self) -> Option<&str> {
+        match self {
+            InteractiveHeader::Text(_) => None,
+            InteractiveHeader::Image(url)
+            | InteractiveHeader::Video(url)
+            | InteractiveHeader::Document(url) => Some(url),
+        }
+    }
+}
+
+impl From<&str> for InteractiveHeader {
+    fn from(text: &str) -> Self {
+        InteractiveHeader::Text(text.to_string())
+    }
+}
+
+impl From<String> for InteractiveHeader {
+    fn from(text: String) -> Self {
+        InteractiveHeader::Text(text)
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -47,61 +136,213 @@ pub struct InteractiveListSection {
     pub rows: Vec<InteractiveListRow>,
 }
 
-#[derive(Serialize, Debug)]
-pub struct PaymentRequest {
-    pub amount: f64,
-    pub currency: String,
-    pub description: String,
-    pub reference_id: String,
+/// A map pin. Sent via `PersistentAction`; also parsed from inbound
+/// location shares (see `IncomingWhatsAppMessage::location`).
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Location {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Place name shown on the pin.
+    pub label: Option<String>,
+    /// Street address; only present on inbound shares.
+    pub address: Option<String>,
 }
 
-#[derive(Serialize, Debug)]
-pub struct ReminderMessage {
-    pub title: String,
-    pub body: String,
-    pub reminder_time: Option<String>,
-    pub action_buttons: Option<Vec<InteractiveButton>>,
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocationError {
+    /// Latitude outside ±90 or longitude outside ±180 (or not a number).
+    OutOfRange { latitude: f64, longitude: f64 },
 }
 
-fn string_or_int<'de, D>(deserializer: D) -> Result<String, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    use serde::de::{self, Visitor};
-    use std::fmt;
+impl fmt::Display for LocationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LocationError::OutOfRange {
+                latitude,
+                longitude,
+            } => write!(
+                f,
+                "Invalid location {},{}: latitude must be within ±90 and longitude within ±180",
+                latitude, longitude
+            ),
+        }
+    }
+}
+
+impl Error for LocationError {}
 
-    struct StringOrIntVisitor;
+impl Location {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            label: None,
+            address: None,
+        }
+    }
 
-    impl<'de> Visitor<'de> for StringOrIntVisitor {
-        type Value = String;
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a string or integer")
+    pub fn validate(&self) -> Result<(), LocationError> {
+        if !(-90.0..=90.0).contains(&self.latitude) || !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(LocationError::OutOfRange {
+                latitude: self.latitude,
+                longitude: self.longitude,
+            });
         }
+        Ok(())
+    }
 
-        fn visit_str<E>(self, value: &str) -> Result<String, E>
-        where
-            E: de::Error,
-        {
-            Ok(value.to_string())
+    /// `geo:lat,long|label`, the `PersistentAction` Twilio turns into a pin.
+    /// A `|` in the label would start a new field, so it becomes a space.
+    pub fn persistent_action(&self) -> String {
+        let mut action = format!("geo:{},{}", self.latitude, self.longitude);
+        if let Some(label) = self.label.as_ref().or(self.address.as_ref()) {
+            action.push('|');
+            action.push_str(&label.replace('|', " "));
         }
+        action
+    }
+}
+
+/// A contact to share as a vCard.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ContactCard {
+    pub name: String,
+    pub organization: Option<String>,
+    pub title: Option<String>,
+    /// Numbers in E.164 format.
+    pub phones: Vec<String>,
+    pub emails: Vec<String>,
+    pub url: Option<String>,
+}
 
-        fn visit_u64<E>(self, value: u64) -> Result<String, E>
-        where
-            E: de::Error,
-        {
-            Ok(value.to_string())
+fn escape_vcard(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace("\r\n", "\\n")
+        .replace(['\r', '\n'], "\\n")
+}
+
+impl ContactCard {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// The card as a vCard 3.0 document (`text/vcard`).
+    pub fn to_vcard(&self) -> String {
+        let name = escape_vcard(&self.name);
+        let mut lines = vec![
+            "BEGIN:VCARD".to_string(),
+            "VERSION:3.0".to_string(),
+            format!("N:{};;;;", name),
+            format!("FN:{}", name),
+        ];
+        if let Some(organization) = &self.organization {
+            lines.push(format!("ORG:{}", escape_vcard(organization)));
+        }
+        if let Some(title) = &self.title {
+            lines.push(format!("TITLE:{}", escape_vcard(title)));
+        }
+        for phone in &self.phones {
+            let wa_id: String = phone.chars().filter(char::is_ascii_digit).collect();
+            lines.push(format!(
+                "TEL;type=CELL;waid={}:{}",
+                wa_id,
+                escape_vcard(phone)
+            ));
+        }
+        for email in &self.emails {
+            lines.push(format!("EMAIL:{}", escape_vcard(email)));
         }
+        if let Some(url) = &self.url {
+            lines.push(format!("URL:{}", escape_vcard(url)));
+        }
+        lines.push("END:VCARD".to_string());
+        lines.join("\r\n") + "\r\n"
+    }
+}
+
+/// A titled group of products in a multi-product message.
+#[derive(Serialize, Debug, Clone)]
+pub struct ProductSection {
+    pub title: String,
+    /// Product retailer IDs from the Meta catalog.
+    pub product_ids: Vec<String>,
+}
+
+/// One line of a cart the customer sent from a catalog.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OrderItem {
+    pub product_retailer_id: String,
+    pub quantity: u32,
+    /// Unit price as a decimal string (e.g. `"12.50"`), whether WhatsApp
+    /// sent it as a string or a number.
+    #[serde(deserialize_with = "crate::decimal::decimal_string")]
+    pub item_price: String,
+    pub currency: String,
+}
 
-        fn visit_i64<E>(self, value: i64) -> Result<String, E>
-        where
-            E: de::Error,
-        {
-            Ok(value.to_string())
+impl OrderItem {
+    /// `item_price * quantity`, exactly; `None` if the price is not a plain
+    /// decimal.
+    pub fn subtotal(&self) -> Option<Decimal> {
+        let price: Decimal = self.item_price.parse().ok()?;
+        price.checked_mul(self.quantity as i128)
+    }
+}
+
+/// WhatsApp's `order` object, sent when a customer submits a cart.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct InboundOrder {
+    pub catalog_id: String,
+    /// Note the customer added to the order.
+    pub text: Option<String>,
+    pub product_items: Vec<OrderItem>,
+}
+
+impl InboundOrder {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Sum of all line subtotals, computed exactly; `None` if the items use
+    /// different currencies or a price is not a plain decimal.
+    pub fn total(&self) -> Option<(Decimal, &str)> {
+        let currency = self.product_items.first()?.currency.as_str();
+        let mut total = Decimal::ZERO;
+        for item in &self.product_items {
+            if item.currency != currency {
+                return None;
+            }
+            total = total.checked_add(item.subtotal()?)?;
         }
+        Some((total, currency))
     }
+}
 
-    deserializer.deserialize_any(StringOrIntVisitor)
+#[derive(Serialize, Debug)]
+pub struct PaymentRequest {
+    pub amount: f64,
+    pub currency: String,
+    pub description: String,
+    pub reference_id: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ReminderMessage {
+    pub title: String,
+    pub body: String,
+    pub reminder_time: Option<String>,
+    pub action_buttons: Option<Vec<InteractiveButton>>,
 }
 
 pub async fn send_whatsapp_text(
@@ -109,31 +350,10 @@ pub async fn send_whatsapp_text(
     to: &str,
     message: &str,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    let to = format!("whatsapp:{}", to);
-    let from = format!("whatsapp:{}", &client.from_phone);
-
-    let params = [
-        ("To", to.as_str()),
-        ("From", from.as_str()),
-        ("Body", message),
-    ];
-
-    let http = Client::new();
-    let response = http
-        .post(&client.base_url())
-        .basic_auth(&client.account_sid, Some(&client.auth_token))
-        .form(&params)
-        .send()
-        .await?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let text = response.text().await?;
-        return Err(format!("Twilio API error {}: {}", status, text).into());
-    }
-
-    let res = response.json::<TwilioMessageResponse>().await?;
-    Ok(res)
+    let to = Address::WhatsApp(client.parse_number(to)?);
+    client
+        .send_message(&to, &OutboundMessage::text(message))
+        .await
 }
 
 pub async fn send_whatsapp_media(
@@ -142,138 +362,292 @@ pub async fn send_whatsapp_media(
     message: &str,
     media_url: &str,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    let to = format!("whatsapp:{}", to);
-    let from = format!("whatsapp:{}", &client.from_phone);
-
-    let params = [
-        ("To", to.as_str()),
-        ("From", from.as_str()),
-        ("Body", message),
-        ("MediaUrl", media_url),
-    ];
+    let to = Address::WhatsApp(client.parse_number(to)?);
+    client
+        .send_message(&to, &OutboundMessage::media(message, media_url))
+        .await
+}
 
-    let http = Client::new();
-    let response = http
-        .post(&client.base_url())
-        .basic_auth(&client.account_sid, Some(&client.auth_token))
-        .form(&params)
-        .send()
-        .await?;
+/// Sends a location pin with `message` as its caption.
+pub async fn send_whatsapp_location(
+    client: &TwilioClient,
+    to: &str,
+    message: &str,
+    location: &Location,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    location.validate()?;
+    let to = Address::WhatsApp(client.parse_number(to)?);
+    let message = OutboundMessage {
+        body: Some(message.to_string()),
+        persistent_actions: vec![location.persistent_action()],
+        ..Default::default()
+    };
+    client.send_message(&to, &message).await
+}
 
-    if !response.status().is_success() {
-        let status = response.status();
-        let text = response.text().await?;
-        return Err(format!("Twilio API error {}: {}", status, text).into());
-    }
+/// Sends `card` as a vCard attachment. Twilio only attaches media by URL,
+/// so `host` is given the `ContactCard::to_vcard()` document and must
+/// publish it as `text/vcard` at a public URL, which it returns.
+pub async fn send_whatsapp_contact<H, Fut>(
+    client: &TwilioClient,
+    to: &str,
+    message: &str,
+    card: &ContactCard,
+    host: H,
+) -> Result<TwilioMessageResponse, Box<dyn Error>>
+where
+    H: FnOnce(String) -> Fut,
+    Fut: Future<Output = Result<String, Box<dyn Error>>>,
+{
+    client.parse_number(to)?;
+    let vcard_url = host(card.to_vcard()).await?;
+    send_whatsapp_media(client, to, message, &vcard_url).await
+}
 
-    let res = response.json::<TwilioMessageResponse>().await?;
-    Ok(res)
+/// Sends `message` as plain text with instructions for sharing a location
+/// through the attachment menu; the reply arrives as
+/// `IncomingWhatsAppMessage::location`. This is not WhatsApp's interactive
+/// location request (the "Send location" button), which Twilio's Content
+/// API does not offer.
+pub async fn send_whatsapp_location_prompt(
+    client: &TwilioClient,
+    to: &str,
+    message: &str,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    let body = format!(
+        "{}\n\n📍 Tap 📎 (or +), choose Location and send your current location.",
+        message
+    );
+    send_whatsapp_text(client, to, &body).await
 }
 
+/// Sends the approved template called `template_name`, in `lang` or the
+/// closest available language (see `content::pick_language`).
 pub async fn send_whatsapp_template(
     client: &TwilioClient,
     to: &str,
     template_name: &str,
     lang: &str,
-    components_json: &str,
+    variables: &ContentVariables,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    let to = format!("whatsapp:{}", to);
-    let from = format!("whatsapp:{}", &client.from_phone);
-
-    let params = [
-        ("To", to.as_str()),
-        ("From", from.as_str()),
-        ("MessagingServiceSid", ""),
-        ("ContentSid", template_name),
-        ("ContentVariables", components_json),
-    ];
-
-    let http = Client::new();
-    let response = http
-        .post(&client.base_url())
-        .basic_auth(&client.account_sid, Some(&client.auth_token))
-        .form(&params)
-        .send()
-        .await?;
+    let template = client.resolve_template(template_name, lang).await?;
+    send_whatsapp_content_template(client, to, &template.sid, Some(variables)).await
+}
 
-    if !response.status().is_success() {
-        let status = response.status();
-        let text = response.text().await?;
-        return Err(format!("Twilio API error {}: {}", status, text).into());
+/// Header and footer are not part of `twilio/quick-reply` or
+/// `twilio/list-picker`, so they are folded into the body as bold and
+/// italic lines; the result must still fit WhatsApp's body limit.
+fn interactive_body(header_text: &str, body_text: &str, footer_text: Option<&str>) -> String {
+    let mut body = String::new();
+    if !header_text.trim().is_empty() {
+        body.push_str(&format!("*{}*\n\n", header_text));
     }
-
-    let res = response.json::<TwilioMessageResponse>().await?;
-    Ok(res)
+    body.push_str(body_text);
+    if let Some(footer) = footer_text {
+        body.push_str(&format!("\n\n_{}_", footer));
+    }
+    body
 }
 
+/// Sends a button message as content created on first use and reused for
+/// identical messages (see `TwilioClient::ephemeral_content`): reply
+/// buttons under a text header become `twilio/quick-reply`, link, phone and
+/// copy-code buttons `twilio/call-to-action`, and anything with a media
+/// header or a mix of both kinds a `twilio/card`.
 pub async fn send_whatsapp_interactive_buttons(
     client: &TwilioClient,
     to: &str,
-    header_text: &str,
+    header: impl Into<InteractiveHeader>,
     body_text: &str,
     footer_text: Option<&str>,
     buttons: Vec<InteractiveButton>,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    if buttons.len() > 3 {
-        return Err("WhatsApp interactive messages support maximum 3 buttons".into());
-    }
-
-    let to = format!("whatsapp:{}", to);
-    let from = format!("whatsapp:{}", &client.from_phone);
-
-    let mut interactive_json = serde_json::json!({
-        "type": "button",
-        "header": {
-            "type": "text",
-            "text": header_text
-        },
-        "body": {
-            "text": body_text
-        },
-        "action": {
-            "buttons": buttons.iter().map(|btn| {
-                serde_json::json!({
-                    "type": "reply",
-                    "reply": {
-                        "id": btn.id,
-                        "title": btn.title
-                    }
-                })
-            }).collect::<Vec<_>>()
+    let header = header.into();
+    validate_buttons(&header, body_text, footer_text, &buttons)?;
+
+    let to = Address::WhatsApp(client.parse_number(to)?);
+
+    let types = button_content_types(&header, body_text, footer_text, buttons)?;
+    let content_sid = client
+        .ephemeral_content(FALLBACK_LANGUAGE, types, None)
+        .await?;
+    client
+        .send_message(&to, &OutboundMessage::content(&content_sid, None))
+        .await
+}
+
+/// Sends buttons whose text holds `{{n}}` placeholders, so every message
+/// shares one content template and only the values change.
+async fn send_button_template(
+    client: &TwilioClient,
+    to: &str,
+    types: ContentTypes,
+    values: &TemplateValues,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    let to = Address::WhatsApp(client.parse_number(to)?);
+    let content_sid = client
+        .ephemeral_content(FALLBACK_LANGUAGE, types, Some(&values.samples))
+        .await?;
+    client
+        .send_message(
+            &to,
+            &OutboundMessage::content(&content_sid, Some(&values.variables)),
+        )
+        .await
+}
+
+/// Values for the `{{n}}` placeholders of a shared template, with the
+/// sample value Twilio stores for each.
+#[derive(Default)]
+struct TemplateValues {
+    variables: ContentVariables,
+    samples: ContentVariables,
+}
+
+impl TemplateValues {
+    /// Records `value` and returns the placeholder to put in its place.
+    fn placeholder(&mut self, value: &str, sample: &str) -> String {
+        self.variables = std::mem::take(&mut self.variables).push(value);
+        self.samples = std::mem::take(&mut self.samples).push(sample);
+        format!("{{{{{}}}}}", self.variables.len())
+    }
+
+    /// `template` with each known placeholder replaced by its value.
+    fn fill(&self, template: &str) -> String {
+        let mut filled = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            let after = &rest[start + 2..];
+            let Some(end) = after.find("}}") else {
+                break;
+            };
+            filled.push_str(&rest[..start]);
+            match self.variables.get(after[..end].trim()) {
+                Some(value) => filled.push_str(value),
+                None => filled.push_str(&rest[start..start + end + 4]),
+            }
+            rest = &after[end + 2..];
         }
-    });
+        filled.push_str(rest);
+        filled
+    }
+}
 
-    if let Some(footer) = footer_text {
-        interactive_json["footer"] = serde_json::json!({
-            "text": footer
+/// Builds button content whose text holds `values`' placeholders. The
+/// message is validated as it will read once the values are filled in, so
+/// an over-long value fails here rather than at WhatsApp.
+fn button_template_types(
+    header: &str,
+    body: &str,
+    footer: Option<&str>,
+    buttons: Vec<InteractiveButton>,
+    values: &TemplateValues,
+) -> Result<ContentTypes, InteractiveError> {
+    let filled_header = values.fill(header);
+    let filled_body = values.fill(body);
+    let filled_footer = footer.map(|footer| values.fill(footer));
+    validate_buttons(
+        &filled_header.as_str().into(),
+        &filled_body,
+        filled_footer.as_deref(),
+        &buttons,
+    )?;
+    validate_text(
+        None,
+        &interactive_body(&filled_header, &filled_body, filled_footer.as_deref()),
+        None,
+    )?;
+    button_content_types(&header.into(), body, footer, buttons)
+}
+
+fn button_content_types(
+    header: &InteractiveHeader,
+    body_text: &str,
+    footer_text: Option<&str>,
+    buttons: Vec<InteractiveButton>,
+) -> Result<ContentTypes, InteractiveError> {
+    let header_text = header.text().unwrap_or_default();
+    let replies_only = buttons.iter().all(InteractiveButton::is_reply);
+    let links_only = !buttons.iter().any(InteractiveButton::is_reply);
+
+    if let Some(media_url) = header.media_url() {
+        return Ok(ContentTypes {
+            card: Some(CardContent {
+                title: body_text.to_string(),
+                subtitle: footer_text.map(str::to_string),
+                media: vec![media_url.to_string()],
+                actions: buttons.into_iter().map(card_action).collect(),
+            }),
+            ..Default::default()
         });
     }
 
-    // Use form data instead of JSON payload
-    let params = [
-        ("To", to.as_str()),
-        ("From", from.as_str()),
-        ("Body", body_text), // Use body_text instead of empty string
-        ("Interactive", &interactive_json.to_string()),
-    ];
-
-    let http = Client::new();
-    let response = http
-        .post(&client.base_url())
-        .basic_auth(&client.account_sid, Some(&client.auth_token))
-        .form(&params) // Use form instead of json
-        .send()
-        .await?;
+    let body = interactive_body(header_text, body_text, footer_text);
+    validate_text(None, &body, None)?;
+    let text = Some(TextContent { body: body.clone() });
+    if replies_only {
+        return Ok(ContentTypes {
+            text,
+            quick_reply: Some(QuickReplyContent {
+                body,
+                actions: buttons
+                    .into_iter()
+                    .filter_map(|button| match button {
+                        InteractiveButton::Reply { id, title } => {
+                            Some(QuickReplyAction { title, id })
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+            }),
+            ..Default::default()
+        });
+    }
+    if links_only {
+        return Ok(ContentTypes {
+            text,
+            call_to_action: Some(CallToActionContent {
+                body,
+                actions: buttons.into_iter().filter_map(call_to_action).collect(),
+            }),
+            ..Default::default()
+        });
+    }
+    Ok(ContentTypes {
+        text,
+        card: Some(CardContent {
+            title: body,
+            subtitle: None,
+            media: Vec::new(),
+            actions: buttons.into_iter().map(card_action).collect(),
+        }),
+        ..Default::default()
+    })
+}
 
-    if !response.status().is_success() {
-        let status = response.status();
-        let text = response.text().await?;
-        return Err(format!("Twilio API error {}: {}", status, text).into());
+fn card_action(button: InteractiveButton) -> CardAction {
+    match button {
+        InteractiveButton::Reply { id, title } => CardAction::QuickReply { title, id },
+        InteractiveButton::Url { title, url } => CardAction::Url { title, url },
+        InteractiveButton::PhoneNumber { title, phone } => CardAction::PhoneNumber { title, phone },
+        InteractiveButton::CopyCode { title, code } => CardAction::CopyCode { title, code },
     }
+}
 
-    let res = response.json::<TwilioMessageResponse>().await?;
-    Ok(res)
+fn call_to_action(button: InteractiveButton) -> Option<CallToAction> {
+    match button {
+        InteractiveButton::Url { title, url } => Some(CallToAction::Url { title, url }),
+        InteractiveButton::PhoneNumber { title, phone } => {
+            Some(CallToAction::PhoneNumber { title, phone })
+        }
+        InteractiveButton::CopyCode { title, code } => Some(CallToAction::CopyCode { title, code }),
+        InteractiveButton::Reply { .. } => None,
+    }
 }
+
+/// Sends a list as `twilio/list-picker` content, created on first use and
+/// reused for identical messages. The list picker has no sections, so rows
+/// are sent in order and section titles are dropped.
 pub async fn send_whatsapp_interactive_list(
     client: &TwilioClient,
     to: &str,
@@ -283,72 +657,140 @@ pub async fn send_whatsapp_interactive_list(
     button_text: &str,
     sections: Vec<InteractiveListSection>,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    let to = format!("whatsapp:{}", to);
-    let from = format!("whatsapp:{}", &client.from_phone);
-
-    let mut interactive_json = serde_json::json!({
-        "type": "list",
-        "header": {
-            "type": "text",
-            "text": header_text
-        },
-        "body": {
-            "text": body_text
-        },
-        "action": {
-            "button": button_text,
-            "sections": sections.iter().map(|section| {
-                let mut section_json = serde_json::json!({
-                    "rows": section.rows.iter().map(|row| {
-                        let mut row_json = serde_json::json!({
-                            "id": row.id,
-                            "title": row.title
-                        });
-                        if let Some(desc) = &row.description {
-                            row_json["description"] = serde_json::Value::String(desc.clone());
-                        }
-                        row_json
-                    }).collect::<Vec<_>>()
-                });
-
-                if let Some(title) = &section.title {
-                    section_json["title"] = serde_json::Value::String(title.clone());
-                }
-                section_json
-            }).collect::<Vec<_>>()
-        }
-    });
-
-    if let Some(footer) = footer_text {
-        interactive_json["footer"] = serde_json::json!({
-            "text": footer
-        });
-    }
-
-    let params = [
-        ("To", to.as_str()),
-        ("From", from.as_str()),
-        ("Body", body_text), // Use body_text instead of empty string
-        ("Interactive", &interactive_json.to_string()),
-    ];
-
-    let http = Client::new();
-    let response = http
-        .post(&client.base_url())
-        .basic_auth(&client.account_sid, Some(&client.auth_token))
-        .form(&params)
-        .send()
+    validate_list(
+        Some(header_text),
+        body_text,
+        footer_text,
+        button_text,
+        &sections,
+    )?;
+
+    let to = Address::WhatsApp(client.parse_number(to)?);
+
+    let body = interactive_body(header_text, body_text, footer_text);
+    validate_text(None, &body, None)?;
+    let types = ContentTypes {
+        text: Some(TextContent { body: body.clone() }),
+        list_picker: Some(ListPickerContent {
+            body,
+            button: button_text.to_string(),
+            items: sections
+                .into_iter()
+                .flat_map(|section| section.rows)
+                .map(|row| ListItem {
+                    id: row.id,
+                    item: row.title,
+                    description: row.description,
+                })
+                .collect(),
+        }),
+        ..Default::default()
+    };
+    let content_sid = client
+        .ephemeral_content(FALLBACK_LANGUAGE, types, None)
+        .await?;
+    client
+        .send_message(&to, &OutboundMessage::content(&content_sid, None))
+        .await
+}
+async fn send_catalog_content(
+    client: &TwilioClient,
+    to: &str,
+    catalog: CatalogContent,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    let to = Address::WhatsApp(client.parse_number(to)?);
+    let types = ContentTypes {
+        catalog: Some(catalog),
+        ..Default::default()
+    };
+    let content_sid = client
+        .ephemeral_content(FALLBACK_LANGUAGE, types, None)
         .await?;
+    client
+        .send_message(&to, &OutboundMessage::content(&content_sid, None))
+        .await
+}
 
-    if !response.status().is_success() {
-        let status = response.status();
-        let text = response.text().await?;
-        return Err(format!("Twilio API error {}: {}", status, text).into());
-    }
+/// Sends a message that opens the whole catalog, optionally showing
+/// `thumbnail_product_id` as its image.
+pub async fn send_whatsapp_catalog(
+    client: &TwilioClient,
+    to: &str,
+    catalog_id: &str,
+    body_text: &str,
+    footer_text: Option<&str>,
+    thumbnail_product_id: Option<&str>,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    validate_catalog(catalog_id, body_text, footer_text, thumbnail_product_id)?;
+    let catalog = CatalogContent {
+        id: catalog_id.to_string(),
+        body: body_text.to_string(),
+        title: None,
+        subtitle: footer_text.map(str::to_string),
+        thumbnail_item_id: thumbnail_product_id.map(str::to_string),
+        items: Vec::new(),
+        dynamic_items: None,
+    };
+    send_catalog_content(client, to, catalog).await
+}
 
-    let res = response.json::<TwilioMessageResponse>().await?;
-    Ok(res)
+/// Sends a single product from the catalog.
+pub async fn send_whatsapp_product(
+    client: &TwilioClient,
+    to: &str,
+    catalog_id: &str,
+    product_id: &str,
+    body_text: &str,
+    footer_text: Option<&str>,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    validate_catalog(catalog_id, body_text, footer_text, Some(product_id))?;
+    let catalog = CatalogContent {
+        id: catalog_id.to_string(),
+        body: body_text.to_string(),
+        title: None,
+        subtitle: footer_text.map(str::to_string),
+        thumbnail_item_id: Some(product_id.to_string()),
+        items: vec![CatalogItem {
+            id: product_id.to_string(),
+            section_title: None,
+        }],
+        dynamic_items: None,
+    };
+    send_catalog_content(client, to, catalog).await
 }
+
+/// Sends up to 30 products grouped into titled sections.
+pub async fn send_whatsapp_product_list(
+    client: &TwilioClient,
+    to: &str,
+    catalog_id: &str,
+    header_text: &str,
+    body_text: &str,
+    footer_text: Option<&str>,
+    sections: Vec<ProductSection>,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    validate_product_list(catalog_id, header_text, body_text, footer_text, &sections)?;
+    let catalog = CatalogContent {
+        id: catalog_id.to_string(),
+        body: body_text.to_string(),
+        title: Some(header_text.to_string()),
+        subtitle: footer_text.map(str::to_string),
+        thumbnail_item_id: None,
+        items: sections
+            .into_iter()
+            .flat_map(|section| {
+                let title = section.title;
+                section.product_ids.into_iter().map(move |id| CatalogItem {
+                    id,
+                    section_title: Some(title.clone()),
+                })
+            })
+            .collect(),
+        dynamic_items: None,
+    };
+    send_catalog_content(client, to, catalog).await
+}
+
 pub async fn send_whatsapp_payment_request(
     client: &TwilioClient,
     to: &str,
@@ -388,65 +830,59 @@ pub async fn send_whatsapp_upi_payment(
 
     send_whatsapp_text(client, to, &message).await
 }
+/// Sends a content template. Inside the 24-hour session window any content
+/// can be sent; outside it Twilio rejects templates WhatsApp has not approved.
 pub async fn send_whatsapp_content_template(
     client: &TwilioClient,
     to: &str,
     content_sid: &str,
-    content_variables: Option<&str>,
+    content_variables: Option<&ContentVariables>,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    let to = format!("whatsapp:{}", to);
-    let from = format!("whatsapp:{}", &client.from_phone);
-
-    let mut params = vec![
-        ("To", to.as_str()),
-        ("From", from.as_str()),
-        ("ContentSid", content_sid),
-    ];
-
-    if let Some(variables) = content_variables {
-        params.push(("ContentVariables", variables));
-    }
-
-    let http = Client::new();
-    let response = http
-        .post(&client.base_url())
-        .basic_auth(&client.account_sid, Some(&client.auth_token))
-        .form(&params)
-        .send()
-        .await?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let text = response.text().await?;
-        return Err(format!("Twilio API error {}: {}", status, text).into());
-    }
+    let to = Address::WhatsApp(client.parse_number(to)?);
+    client
+        .send_message(
+            &to,
+            &OutboundMessage::content(content_sid, content_variables),
+        )
+        .await
+}
 
-    let res = response.json::<TwilioMessageResponse>().await?;
-    Ok(res)
+/// Like `send_whatsapp_content_template`, but first fetches the approval
+/// status and fails with `ContentError::NotApproved` unless WhatsApp has
+/// approved the template. Costs an extra request per send.
+pub async fn send_whatsapp_content_template_checked(
+    client: &TwilioClient,
+    to: &str,
+    content_sid: &str,
+    content_variables: Option<&ContentVariables>,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    client.ensure_whatsapp_approved(content_sid).await?;
+    send_whatsapp_content_template(client, to, content_sid, content_variables).await
 }
+
+/// Sends a reminder. With action buttons, the title, body and time are
+/// content variables of one shared template per set of buttons.
 pub async fn send_whatsapp_reminder(
     client: &TwilioClient,
     to: &str,
     reminder: ReminderMessage,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    let mut message = format!("🔔 Reminder: {}\n\n{}", reminder.title, reminder.body);
+    if let Some(buttons) = reminder.action_buttons {
+        let mut values = TemplateValues::default();
+        let header = format!("🔔 {}", values.placeholder(&reminder.title, "Team meeting"));
+        let body = values.placeholder(&reminder.body, "Weekly planning in room 4");
+        let footer = reminder
+            .reminder_time
+            .as_deref()
+            .map(|time| values.placeholder(time, "Mon 10:00"));
+        let types = button_template_types(&header, &body, footer.as_deref(), buttons, &values)?;
+        return send_button_template(client, to, types, &values).await;
+    }
 
+    let mut message = format!("🔔 Reminder: {}\n\n{}", reminder.title, reminder.body);
     if let Some(time) = &reminder.reminder_time {
         message.push_str(&format!("\n⏰ Scheduled for: {}", time));
     }
-
-    // If there are action buttons, send as interactive message
-    if let Some(buttons) = reminder.action_buttons {
-        return send_whatsapp_interactive_buttons(
-            client,
-            to,
-            &format!("🔔 {}", reminder.title),
-            &reminder.body,
-            reminder.reminder_time.as_deref(),
-            buttons,
-        )
-        .await;
-    }
     send_whatsapp_text(client, to, &message).await
 }
 
@@ -486,10 +922,7 @@ pub async fn send_whatsapp_quick_replies(
         let buttons = choices
             .into_iter()
             .enumerate()
-            .map(|(i, choice)| InteractiveButton {
-                id: format!("choice_{}", i),
-                title: choice.to_string(),
-            })
+            .map(|(i, choice)| InteractiveButton::reply(&format!("choice_{}", i), choice))
             .collect();
 
         return send_whatsapp_interactive_buttons(
@@ -505,6 +938,9 @@ pub async fn send_whatsapp_quick_replies(
 }
 
 // NEW: Appointment Reminder
+/// Sends an appointment reminder with confirm/reschedule/cancel buttons. The
+/// appointment details are content variables, so all reminders share one
+/// template (two, with and without a doctor).
 pub async fn send_whatsapp_appointment_reminder(
     client: &TwilioClient,
     to: &str,
@@ -513,42 +949,40 @@ pub async fn send_whatsapp_appointment_reminder(
     location: &str,
     doctor_name: Option<&str>,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    let doctor_info = doctor_name
-        .map(|name| format!("👨‍⚕️ With: Dr. {}\n", name))
-        .unwrap_or_default();
-
     let buttons = vec![
-        InteractiveButton {
-            id: "confirm".to_string(),
-            title: "✅ Confirm".to_string(),
-        },
-        InteractiveButton {
-            id: "reschedule".to_string(),
-            title: "📅 Reschedule".to_string(),
-        },
-        InteractiveButton {
-            id: "cancel".to_string(),
-            title: "❌ Cancel".to_string(),
-        },
+        InteractiveButton::reply("confirm", "✅ Confirm"),
+        InteractiveButton::reply("reschedule", "📅 Reschedule"),
+        InteractiveButton::reply("cancel", "❌ Cancel"),
     ];
 
-    let body = format!(
-        "📅 Date: {}\n⏰ Time: {}\n📍 Location: {}\n{}",
-        appointment_date, appointment_time, location, doctor_info
+    let mut values = TemplateValues::default();
+    let mut body = format!(
+        "📅 Date: {}\n⏰ Time: {}\n📍 Location: {}",
+        values.placeholder(appointment_date, "12/1"),
+        values.placeholder(appointment_time, "3pm"),
+        values.placeholder(location, "Main Street Clinic")
     );
+    if let Some(name) = doctor_name {
+        body.push_str(&format!(
+            "\n👨‍⚕️ With: Dr. {}",
+            values.placeholder(name, "Smith")
+        ));
+    }
 
-    send_whatsapp_interactive_buttons(
-        client,
-        to,
+    let types = button_template_types(
         "🩺 Appointment Reminder",
         &body,
         Some("Please confirm your appointment"),
         buttons,
-    )
-    .await
+        &values,
+    )?;
+    send_button_template(client, to, types, &values).await
 }
 
 // NEW: Order Status Messages
+/// Sends an order update with track/support buttons. The order details are
+/// content variables, so updates share one template per combination of
+/// optional fields.
 pub async fn send_whatsapp_order_status(
     client: &TwilioClient,
     to: &str,
@@ -557,37 +991,161 @@ pub async fn send_whatsapp_order_status(
     tracking_url: Option<&str>,
     estimated_delivery: Option<&str>,
 ) -> Result<TwilioMessageResponse, Box<dyn Error>> {
-    let mut message = format!(
+    let mut values = TemplateValues::default();
+    let mut body = format!(
         "📦 Order Update\n\nOrder ID: {}\nStatus: {}",
-        order_id, status
+        values.placeholder(order_id, "ORD-1234"),
+        values.placeholder(status, "Shipped")
     );
-
     if let Some(delivery) = estimated_delivery {
-        message.push_str(&format!("\n🚚 Estimated Delivery: {}", delivery));
+        body.push_str(&format!(
+            "\n🚚 Estimated Delivery: {}",
+            values.placeholder(delivery, "Tomorrow")
+        ));
     }
-
     if let Some(url) = tracking_url {
-        message.push_str(&format!("\n\n🔗 Track your order: {}", url));
+        body.push_str(&format!(
+            "\n\n🔗 Track your order: {}",
+            values.placeholder(url, "https://example.com/track/ORD-1234")
+        ));
     }
 
     let buttons = vec![
-        InteractiveButton {
-            id: "track_order".to_string(),
-            title: "📍 Track Order".to_string(),
-        },
-        InteractiveButton {
-            id: "contact_support".to_string(),
-            title: "💬 Support".to_string(),
-        },
+        InteractiveButton::reply("track_order", "📍 Track Order"),
+        InteractiveButton::reply("contact_support", "💬 Support"),
     ];
 
-    send_whatsapp_interactive_buttons(
-        client,
-        to,
+    let types = button_template_types(
         "📦 Order Status Update",
-        &message,
+        &body,
         Some("Need help with your order?"),
         buttons,
-    )
-    .await
+        &values,
+    )?;
+    send_button_template(client, to, types, &values).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_labels_cannot_add_fields() {
+        assert_eq!(
+            Location::new(51.5, -0.12)
+                .label("Pub | Bar")
+                .persistent_action(),
+            "geo:51.5,-0.12|Pub   Bar"
+        );
+        let shared = Location {
+            address: Some("1 Main St|2nd floor".to_string()),
+            ..Location::new(1.0, 2.0)
+        };
+        assert_eq!(shared.persistent_action(), "geo:1,2|1 Main St 2nd floor");
+    }
+
+    #[test]
+    fn vcard_values_are_escaped() {
+        let card = ContactCard {
+            phones: vec!["+1 415;x\r\nNOTE:hi".to_string()],
+            url: Some("https://example.com/a,b;c\nEND:VCARD".to_string()),
+            ..ContactCard::new("Doe, Jane")
+        };
+        assert_eq!(
+            card.to_vcard(),
+            concat!(
+                "BEGIN:VCARD\r\n",
+                "VERSION:3.0\r\n",
+                "N:Doe\\, Jane;;;;\r\n",
+                "FN:Doe\\, Jane\r\n",
+                "TEL;type=CELL;waid=1415:+1 415\\;x\\nNOTE:hi\r\n",
+                "URL:https://example.com/a\\,b\\;c\\nEND:VCARD\r\n",
+                "END:VCARD\r\n",
+            )
+        );
+    }
+
+    #[test]
+    fn locations_must_be_on_the_globe() {
+        assert_eq!(Location::new(90.0, -180.0).validate(), Ok(()));
+        for (latitude, longitude) in [(90.5, 0.0), (0.0, -180.5), (f64::NAN, 0.0)] {
+            assert!(matches!(
+                Location::new(latitude, longitude).validate(),
+                Err(LocationError::OutOfRange { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn order_prices_parse_from_strings_or_numbers() {
+        let order = InboundOrder::from_json(
+            r#"{"catalog_id":"123","text":null,"product_items":[
+                {"product_retailer_id":"a","quantity":3,"item_price":"0.10","currency":"USD"},
+                {"product_retailer_id":"b","quantity":2,"item_price":12.5,"currency":"USD"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(order.product_items[0].item_price, "0.10");
+        assert_eq!(order.product_items[1].item_price, "12.5");
+        let subtotal = |index: usize| order.product_items[index].subtotal().unwrap();
+        assert_eq!(subtotal(0).to_string(), "0.30");
+        assert_eq!(subtotal(1).to_string(), "25.0");
+        let (total, currency) = order.total().unwrap();
+        assert_eq!((total.to_string().as_str(), currency), ("25.30", "USD"));
+    }
+
+    #[test]
+    fn order_totals_need_one_currency_and_plain_decimals() {
+        let item = |price: &str, currency: &str| OrderItem {
+            product_retailer_id: "a".to_string(),
+            quantity: 1,
+            item_price: price.to_string(),
+            currency: currency.to_string(),
+        };
+        let order = |items| InboundOrder {
+            catalog_id: "123".to_string(),
+            text: None,
+            product_items: items,
+        };
+        assert_eq!(
+            order(vec![item("5", "INR"), item("5", "USD")]).total(),
+            None
+        );
+        assert_eq!(order(vec![item("1e3", "USD")]).total(), None);
+        assert_eq!(
+            order(vec![item("-0.5", "EUR"), item("2", "EUR")]).total(),
+            Some(("1.5".parse().unwrap(), "EUR"))
+        );
+    }
+
+    #[test]
+    fn template_values_fill_their_placeholders() {
+        let mut values = TemplateValues::default();
+        let text = format!(
+            "Order {} is {} ({{{{9}}}})",
+            values.placeholder("{{2}}", "ORD-1"),
+            values.placeholder("shipped", "Shipped")
+        );
+        assert_eq!(text, "Order {{1}} is {{2}} ({{9}})");
+        assert_eq!(values.fill(&text), "Order {{2}} is shipped ({{9}})");
+    }
+
+    #[tokio::test]
+    async fn long_values_fail_before_any_request() {
+        let client = TwilioClient::with_api_key("AC123", "SK123", "secret", "+14155550100");
+        let error = send_whatsapp_order_status(
+            &client,
+            "+14155550123",
+            &"A".repeat(2000),
+            "Shipped",
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<InteractiveError>(),
+            Some(InteractiveError::TooLong { .. })
+        ));
+    }
 }
@@ -0,0 +1,356 @@
+// whatsapp_session.rs
+
+use crate::client::TwilioClient;
+use crate::content::ContentVariables;
+use crate::phone::PhoneNumber;
+use crate::webhook::IncomingWhatsAppMessage;
+use crate::whatsapp::{
+    send_whatsapp_content_template, send_whatsapp_template, send_whatsapp_text,
+    TwilioMessageResponse,
+};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// How long after a customer's last message free-form replies are allowed.
+pub const SESSION_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionError {
+    /// The customer has not messaged within the session window, so only
+    /// approved templates can be sent.
+    WindowClosed {
+        wa_id: String,
+        last_inbound: Option<SystemTime>,
+    },
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionError::WindowClosed {
+                wa_id,
+                last_inbound: None,
+            } => write!(
+                f,
+                "WhatsApp session with {} is closed: the customer has never messaged",
+                wa_id
+            ),
+            SessionError::WindowClosed {
+                wa_id,
+                last_inbound: Some(at),
+            } => {
+                let ago = at.elapsed().unwrap_or_default().as_secs() / 3600;
+                write!(
+                    f,
+                    "WhatsApp session with {} is closed: last customer message was {}h ago",
+                    wa_id, ago
+                )
+            }
+        }
+    }
+}
+
+impl Error for SessionError {}
+
+/// Where the time of each customer's last inbound message is kept.
+/// Implement this to share sessions across processes (e.g. in Redis).
+pub trait SessionStore: Send + Sync {
+    fn record_inbound(&self, wa_id: &str, at: SystemTime);
+    fn last_inbound(&self, wa_id: &str) -> Option<SystemTime>;
+
+    /// Drops customers whose last message was at or before `cutoff`, as
+    /// their windows have closed. Stores that expire keys themselves (e.g.
+    /// with a Redis TTL) can keep this default.
+    fn forget_before(&self, _cutoff: SystemTime) {}
+}
+
+/// Process-local `SessionStore`. Customers are forgotten once their window
+/// has closed, so it only holds those who messaged within the window.
+#[derive(Debug, Default)]
+pub struct MemorySessionStore {
+    last_inbound: Mutex<HashMap<String, SystemTime>>,
+}
+
+impl SessionStore for MemorySessionStore {
+    fn record_inbound(&self, wa_id: &str, at: SystemTime) {
+        let mut last_inbound = self.last_inbound.lock().unwrap();
+        let entry = last_inbound.entry(wa_id.to_string()).or_insert(at);
+        if at > *entry {
+            *entry = at;
+        }
+    }
+
+    fn last_inbound(&self, wa_id: &str) -> Option<SystemTime> {
+        self.last_inbound.lock().unwrap().get(wa_id).copied()
+    }
+
+    fn forget_before(&self, cutoff: SystemTime) {
+        self.last_inbound
+            .lock()
+            .unwrap()
+            .retain(|_, at| *at > cutoff);
+    }
+}
+
+/// The `WaId` Twilio reports for a number: its E.164 form without the `+`.
+pub fn wa_id(number: &PhoneNumber) -> String {
+    number.e164().trim_start_matches('+').to_string()
+}
+
+/// Tracks the 24-hour customer service window per WhatsApp user.
+#[derive(Clone)]
+pub struct SessionTracker {
+    store: Arc<dyn SessionStore>,
+    pub window: Duration,
+}
+
+impl Default for SessionTracker {
+    fn default() -> Self {
+        Self::in_memory()
+    }
+}
+
+impl SessionTracker {
+    pub fn new(store: Arc<dyn SessionStore>) -> Self {
+        Self {
+            store,
+            window: SESSION_WINDOW,
+        }
+    }
+
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(MemorySessionStore::default()))
+    }
+
+    pub fn record_inbound(&self, wa_id: &str) {
+        let now = SystemTime::now();
+        self.store.record_inbound(wa_id, now);
+        if let Some(cutoff) = now.checked_sub(self.window) {
+            self.store.forget_before(cutoff);
+        }
+    }
+
+    /// Records an inbound webhook message, keyed by its `WaId` (or the
+    /// sender's number when Twilio omits it).
+    pub fn record_message(&self, message: &IncomingWhatsAppMessage) {
+        let id = match &message.wa_id {
+            Some(id) if !id.is_empty() => id.clone(),
+            _ => match message.sender() {
+                Ok(number) => wa_id(&number),
+                Err(_) => return,
+            },
+        };
+        self.record_inbound(&id);
+    }
+
+    /// When the window for `wa_id` closes; `None` if it has never opened.
+    pub fn window_closes_at(&self, wa_id: &str) -> Option<SystemTime> {
+        self.store.last_inbound(wa_id).map(|at| at + self.window)
+    }
+
+    pub fn is_open(&self, wa_id: &str) -> bool {
+        self.window_closes_at(wa_id)
+            .is_some_and(|closes_at| closes_at > SystemTime::now())
+    }
+
+    /// Fails with `SessionError::WindowClosed` unless the window is open.
+    pub fn ensure_open(&self, wa_id: &str) -> Result<(), SessionError> {
+        if self.is_open(wa_id) {
+            return Ok(());
+        }
+        Err(SessionError::WindowClosed {
+            wa_id: wa_id.to_string(),
+            last_inbound: self.store.last_inbound(wa_id),
+        })
+    }
+
+    /// The template to send instead of free-form text to `wa_id`: `None`
+    /// while the window is open, otherwise `fallback`, or the
+    /// `WindowClosed` error when the fallback is `SessionFallback::Refuse`.
+    pub fn fallback_for<'a>(
+        &self,
+        wa_id: &str,
+        fallback: &'a SessionFallback,
+    ) -> Result<Option<&'a SessionFallback>, SessionError> {
+        match (self.ensure_open(wa_id), fallback) {
+            (Ok(()), _) => Ok(None),
+            (Err(closed), SessionFallback::Refuse) => Err(closed),
+            (Err(_), fallback) => Ok(Some(fallback)),
+        }
+    }
+}
+
+/// What `send_whatsapp_text_in_session` does when the window is closed.
+#[derive(Debug, Clone)]
+pub enum SessionFallback {
+    /// Return `SessionError::WindowClosed` without calling Twilio.
+    Refuse,
+    /// Send an approved template, looked up by name and language.
+    Template {
+        name: String,
+        language: String,
+        variables: ContentVariables,
+    },
+    /// Send an approved template by Content SID.
+    Content {
+        content_sid: String,
+        variables: Option<ContentVariables>,
+    },
+}
+
+/// Sends free-form text while the session window is open, otherwise
+/// applies `fallback`.
+pub async fn send_whatsapp_text_in_session(
+    client: &TwilioClient,
+    sessions: &SessionTracker,
+    to: &str,
+    message: &str,
+    fallback: &SessionFallback,
+) -> Result<TwilioMessageResponse, Box<dyn Error>> {
+    match sessions.fallback_for(&wa_id(&client.parse_number(to)?), fallback)? {
+        Some(SessionFallback::Template {
+            name,
+            language,
+            variables,
+        }) => send_whatsapp_template(client, to, name, language, variables).await,
+        Some(SessionFallback::Content {
+            content_sid,
+            variables,
+        }) => send_whatsapp_content_template(client, to, content_sid, variables.as_ref()).await,
+        // `fallback_for` returns `Refuse` as an error, so only an open
+        // window gets here.
+        None | Some(SessionFallback::Refuse) => send_whatsapp_text(client, to, message).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    fn tracker() -> (Arc<MemorySessionStore>, SessionTracker) {
+        let store = Arc::new(MemorySessionStore::default());
+        (store.clone(), SessionTracker::new(store))
+    }
+
+    fn message(from: &str, wa_id: Option<&str>) -> IncomingWhatsAppMessage {
+        serde_json::from_value(serde_json::json!({
+            "From": from,
+            "To": "whatsapp:+14155550199",
+            "Body": "Hi",
+            "MessageSid": "SM123",
+            "WaId": wa_id,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn windows_close_after_the_session_window() {
+        let (store, sessions) = tracker();
+        let now = SystemTime::now();
+        store.record_inbound("447700900001", now - HOUR);
+        store.record_inbound("447700900002", now - 25 * HOUR);
+
+        assert!(sessions.is_open("447700900001"));
+        assert!(!sessions.is_open("447700900002"));
+        assert!(!sessions.is_open("447700900003"));
+        assert_eq!(
+            sessions.ensure_open("447700900002"),
+            Err(SessionError::WindowClosed {
+                wa_id: "447700900002".to_string(),
+                last_inbound: Some(now - 25 * HOUR),
+            })
+        );
+        assert_eq!(
+            sessions.ensure_open("447700900003"),
+            Err(SessionError::WindowClosed {
+                wa_id: "447700900003".to_string(),
+                last_inbound: None,
+            })
+        );
+    }
+
+    #[test]
+    fn messages_are_keyed_by_wa_id_or_sender() {
+        let (store, sessions) = tracker();
+        sessions.record_message(&message("whatsapp:+447700900001", Some("447700900009")));
+        sessions.record_message(&message("whatsapp:+447700900002", Some("")));
+        sessions.record_message(&message("whatsapp:+447700900003", None));
+        sessions.record_message(&message("whatsapp:not-a-number", None));
+
+        for id in ["447700900009", "447700900002", "447700900003"] {
+            assert!(sessions.is_open(id), "{}", id);
+        }
+        assert!(store.last_inbound("447700900001").is_none());
+        assert_eq!(store.last_inbound.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn closed_windows_are_forgotten() {
+        let (store, sessions) = tracker();
+        let now = SystemTime::now();
+        store.record_inbound("447700900001", now - 25 * HOUR);
+        store.record_inbound("447700900002", now - HOUR);
+
+        sessions.record_inbound("447700900003");
+        assert!(store.last_inbound("447700900001").is_none());
+        assert!(store.last_inbound("447700900002").is_some());
+        assert!(store.last_inbound("447700900003").is_some());
+    }
+
+    #[test]
+    fn fallbacks_apply_only_to_closed_windows() {
+        let (store, sessions) = tracker();
+        store.record_inbound("447700900001", SystemTime::now());
+        let template = SessionFallback::Template {
+            name: "follow_up".to_string(),
+            language: "en".to_string(),
+            variables: ContentVariables::new(),
+        };
+        let content = SessionFallback::Content {
+            content_sid: "HX123".to_string(),
+            variables: None,
+        };
+
+        for fallback in [&SessionFallback::Refuse, &template, &content] {
+            assert!(matches!(
+                sessions.fallback_for("447700900001", fallback),
+                Ok(None)
+            ));
+        }
+        assert!(matches!(
+            sessions.fallback_for("447700900002", &SessionFallback::Refuse),
+            Err(SessionError::WindowClosed { .. })
+        ));
+        assert!(matches!(
+            sessions.fallback_for("447700900002", &template),
+            Ok(Some(SessionFallback::Template { name, .. })) if name == "follow_up"
+        ));
+        assert!(matches!(
+            sessions.fallback_for("447700900002", &content),
+            Ok(Some(SessionFallback::Content { content_sid, .. })) if content_sid == "HX123"
+        ));
+    }
+
+    #[tokio::test]
+    async fn refused_sends_never_reach_twilio() {
+        let client = TwilioClient::with_api_key("AC123", "SK123", "secret", "+14155550199");
+        let error = send_whatsapp_text_in_session(
+            &client,
+            &SessionTracker::in_memory(),
+            "+447700900001",
+            "Hello",
+            &SessionFallback::Refuse,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SessionError>(),
+            Some(SessionError::WindowClosed { wa_id, last_inbound: None }) if wa_id == "447700900001"
+        ));
+    }
+}
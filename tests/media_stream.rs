@@ -0,0 +1,165 @@
+use actix_web::{App, HttpServer};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use futures_util::{SinkExt, StreamExt};
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha1::Sha1;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+use twilio_rs::media_stream::{build_media_stream_scope, media_stream_handler, MediaStreamEvent};
+
+const STREAM_URL: &str = "wss://example.com/twilio/stream";
+const AUTH_TOKEN: &str = "media_stream_test_token";
+const STREAM_SID: &str = "MZ00000000000000000000000000000000";
+
+fn sign(url: &str) -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(AUTH_TOKEN.as_bytes()).unwrap();
+    mac.update(url.as_bytes());
+    STANDARD.encode(mac.finalize().into_bytes())
+}
+
+/// Starts a server whose handler records each event name and answers the
+/// first media chunk with `clear` followed by a `mark`.
+fn serve(events: Arc<Mutex<Vec<String>>>) -> SocketAddr {
+    std::env::set_var("TWILIO_AUTH_TOKEN", AUTH_TOKEN);
+
+    let server = HttpServer::new(move || {
+        let events = events.clone();
+        let handler = media_stream_handler(move |event, mut sender| {
+            let events = events.clone();
+            async move {
+                let name = serde_json::to_value(&event)
+                    .ok()
+                    .and_then(|v| v["event"].as_str().map(str::to_string))
+                    .unwrap_or_else(|| "unrecognised".to_string());
+                events.lock().unwrap().push(name);
+                if let MediaStreamEvent::Media { .. } = event {
+                    sender.clear().await.unwrap();
+                    sender.mark("greeting").await.unwrap();
+                }
+            }
+        });
+        App::new().service(build_media_stream_scope(STREAM_URL, handler))
+    })
+    .workers(1)
+    .bind(("127.0.0.1", 0))
+    .unwrap();
+    let addr = server.addrs()[0];
+    actix_web::rt::spawn(server.run());
+    addr
+}
+
+fn event(name: &str, body: Value) -> Message {
+    let mut event = json!({ "event": name, "sequenceNumber": "1", "streamSid": STREAM_SID });
+    event
+        .as_object_mut()
+        .unwrap()
+        .extend(body.as_object().unwrap().clone());
+    Message::Text(event.to_string())
+}
+
+async fn next_json<S>(socket: &mut S) -> Value
+where
+    S: StreamExt<Item = Result<Message, WsError>> + Unpin,
+{
+    match socket.next().await {
+        Some(Ok(Message::Text(text))) => serde_json::from_str(&text).unwrap(),
+        other => panic!("expected a text frame, got {:?}", other),
+    }
+}
+
+#[actix_web::test]
+async fn stream_lifecycle_with_clear_and_mark_replies() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let addr = serve(events.clone());
+
+    let mut request = format!("ws://{}/twilio/stream", addr)
+        .into_client_request()
+        .unwrap();
+    request
+        .headers_mut()
+        .insert("X-Twilio-Signature", sign(STREAM_URL).parse().unwrap());
+    let (mut socket, _) = tokio_tungstenite::connect_async(request).await.unwrap();
+
+    let connected = json!({ "event": "connected", "protocol": "Call", "version": "1.0.0" });
+    socket
+        .send(Message::Text(connected.to_string()))
+        .await
+        .unwrap();
+    let start = json!({ "start": {
+        "accountSid": "AC123",
+        "streamSid": STREAM_SID,
+        "callSid": "CA123",
+        "tracks": ["inbound"],
+        "customParameters": { "caller": "test" },
+        "mediaFormat": { "encoding": "audio/x-mulaw", "sampleRate": 8000, "channels": 1 }
+    }});
+    socket.send(event("start", start)).await.unwrap();
+    let media = json!({ "media": {
+        "track": "inbound",
+        "chunk": "1",
+        "timestamp": "5",
+        "payload": STANDARD.encode([0xFFu8; 160])
+    }});
+    socket.send(event("media", media)).await.unwrap();
+
+    let clear = next_json(&mut socket).await;
+    assert_eq!(clear, json!({ "event": "clear", "streamSid": STREAM_SID }));
+    let mark = next_json(&mut socket).await;
+    assert_eq!(
+        mark,
+        json!({ "event": "mark", "streamSid": STREAM_SID, "mark": { "name": "greeting" } })
+    );
+
+    socket
+        .send(event("mark", json!({ "mark": { "name": "greeting" } })))
+        .await
+        .unwrap();
+    socket
+        .send(Message::Text(r#"{"event":"bogus"}"#.to_string()))
+        .await
+        .unwrap();
+    let stop = json!({ "stop": { "accountSid": "AC123", "callSid": "CA123" } });
+    socket.send(event("stop", stop)).await.unwrap();
+
+    // The server closes the socket once the stop event has been handled.
+    while let Some(Ok(message)) = socket.next().await {
+        if message.is_close() {
+            break;
+        }
+    }
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "connected",
+            "start",
+            "media",
+            "mark",
+            "unrecognised",
+            "stop"
+        ]
+    );
+}
+
+#[actix_web::test]
+async fn unsigned_upgrades_are_rejected() {
+    let addr = serve(Arc::new(Mutex::new(Vec::new())));
+
+    let mut request = format!("ws://{}/twilio/stream", addr)
+        .into_client_request()
+        .unwrap();
+    request.headers_mut().insert(
+        "X-Twilio-Signature",
+        sign("wss://attacker.example/twilio/stream")
+            .parse()
+            .unwrap(),
+    );
+    match tokio_tungstenite::connect_async(request).await {
+        Err(WsError::Http(response)) => assert_eq!(response.status(), 401),
+        other => panic!("expected 401, got {:?}", other.map(|(_, r)| r.status())),
+    }
+}